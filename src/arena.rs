@@ -0,0 +1,89 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        alloc::Layout,
+        cell::Cell,
+        ptr::{ copy_nonoverlapping, write },
+        slice::from_raw_parts_mut,
+    },
+};
+
+/// A bump allocator carving values out of one `Array<u8>` buffer
+///
+/// Values are never dropped, and their memory is only reclaimed by [`Arena::reset`]
+pub struct Arena {
+    buf: Array<u8>,
+    used: Cell<usize>,
+} impl Arena {
+    /// Create a new Arena with a buffer of `size` bytes
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(size: usize) -> Result<Self, ArrayError> {
+        Ok(Arena { buf: Array::new(size)?, used: Cell::new(0) })
+    }
+
+    /// Reserve space for `layout`, returning a pointer to it
+    fn alloc_raw(&self, layout: Layout) -> Result<*mut u8, ArrayError> {
+        let base = self.buf.as_ptr() as usize;
+        let start = (base + self.used.get())
+            .checked_next_multiple_of(layout.align())
+            .map(|addr| addr - base);
+        let end = start.and_then(|start| start.checked_add(layout.size()));
+        match (start, end) {
+            (Some(start), Some(end)) if end <= self.buf.cap() => {
+                self.used.set(end);
+                // start <= end <= cap, so this stays in the buffer
+                Ok(unsafe { self.buf.as_ptr().add(start) })
+            }
+            _ => Err(ArrayError::new(format!(
+                "arena exhausted: {} of {} bytes used, {} requested",
+                self.used.get(), self.buf.cap(), layout.size()
+            ))),
+        }
+    }
+
+    /// Move `val` into the arena
+    ///
+    /// Error states:
+    ///  * there isn't enough room left for a `T`
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> Result<&mut T, ArrayError> {
+        let ptr = self.alloc_raw(Layout::new::<T>())? as *mut T;
+        unsafe {
+            // The space is aligned, unused, and never handed out again until `reset`
+            write(ptr, val);
+            Ok(&mut *ptr)
+        }
+    }
+
+    /// Copy `src` into the arena
+    ///
+    /// Error states:
+    ///  * there isn't enough room left for `src`
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_copy<T: Copy>(&self, src: &[T]) -> Result<&mut [T], ArrayError> {
+        let layout = match Layout::array::<T>(src.len()) {
+            Ok(layout) => layout,
+            Err(_) => return Err(ArrayError::new("Overflow when getting layout size")),
+        };
+        let ptr = self.alloc_raw(layout)? as *mut T;
+        unsafe {
+            copy_nonoverlapping(src.as_ptr(), ptr, src.len());
+            Ok(from_raw_parts_mut(ptr, src.len()))
+        }
+    }
+
+    /// Bytes handed out so far, including alignment padding
+    pub fn used(&self) -> usize { self.used.get() }
+
+    pub fn cap(&self) -> usize { self.buf.cap() }
+
+    /// Free every allocation at once, without dropping the values
+    pub fn reset(&mut self) {
+        self.used.set(0);
+    }
+}
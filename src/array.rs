@@ -1,4 +1,6 @@
 
+#[cfg(feature = "debug-guard")]
+use crate::guard;
 use {
     crate::{
         error::ArrayError,
@@ -8,16 +10,63 @@ use {
             Debug, Display,
             Formatter, Result as FmtResult
         },
-        cmp::Ordering,
-        ptr::{ NonNull, write, read },
+        ptr::{ NonNull, write, read, copy_nonoverlapping, drop_in_place, slice_from_raw_parts_mut },
         alloc::{
-            alloc_zeroed, Layout
+            alloc_zeroed, dealloc as free, Layout
         },
-        mem::{size_of, align_of},
+        mem::{size_of, align_of, swap, forget, ManuallyDrop},
+        slice::{ from_raw_parts, from_raw_parts_mut, Split, ChunkBy, SliceIndex, Chunks, ChunksMut, Iter, IterMut },
+        borrow::{ Borrow, BorrowMut },
+        hash::{ Hash, Hasher },
+        str::FromStr,
+        hint::black_box,
+        iter::FromIterator,
+        cmp::Ordering,
+        collections::HashSet,
+        ops::{ Index, IndexMut, Range },
     },
 };
 
 
+/// The size and alignment of an array [T; `len`], usable in const contexts
+/// such as sizing static buffers
+///
+/// This is the layout of the elements only, with the `debug-guard` feature
+/// [`alloc`] pads it with canary bytes
+///
+/// The fields are private so every `ArrayLayout` comes from [`ArrayLayout::of`]
+/// and is accepted by `Layout`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ArrayLayout {
+    size: usize,
+    align: usize,
+} impl ArrayLayout {
+    /// The layout of [T; `len`], or `None` if its size overflows `isize`
+    pub const fn of<T>(len: usize) -> Option<Self> {
+        let align = align_of::<T>();
+        match size_of::<T>().checked_mul(len) {
+            // `Layout` limits the size, rounded up to `align`, to `isize::MAX`
+            Some(size) if size <= isize::MAX as usize - (align - 1) => Some(ArrayLayout { size, align }),
+            _ => None,
+        }
+    }
+
+    /// Size in bytes
+    pub const fn size(self) -> usize { self.size }
+
+    /// Alignment in bytes
+    pub const fn align(self) -> usize { self.align }
+
+    /// Convert to a `std::alloc::Layout`
+    pub const fn to_layout(self) -> Layout {
+        match Layout::from_size_align(self.size, self.align) {
+            Ok(layout) => layout,
+            // `of` only builds layouts `Layout` accepts
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
 /// Allocate space for an array [T; `len`]
 ///
 /// Error states: 
@@ -25,6 +74,12 @@ use {
 /// * `len * size_of::<T>()` overflows
 /// 
 /// Returns a non-null pointer to the beginning of the Array
+///
+/// With the `debug-guard` feature the buffer is surrounded by canary bytes,
+/// which [`dealloc`] checks
+///
+/// With the `failpoints` feature, failures can be forced with
+/// [`failpoints::fail_next`](crate::failpoints::fail_next)
 pub fn alloc<T>(len: usize) -> Result<NonNull<T>, ArrayError> {
     // Get the size of the allocation
     let size = match size_of::<T>().checked_mul(len) {
@@ -34,37 +89,159 @@ pub fn alloc<T>(len: usize) -> Result<NonNull<T>, ArrayError> {
     };
     // Get the align of T
     let align = align_of::<T>();
-    let layout = match Layout::from_size_align(size, align) {
+    #[cfg(not(feature = "debug-guard"))]
+    let total = size;
+    #[cfg(feature = "debug-guard")]
+    let total = match guard::padded_size(size, align) {
+        Some(n) => n,
+        None => return Err(ArrayError::new("Overflow when getting layout size")),
+    };
+    let layout = match Layout::from_size_align(total, align) {
         Ok(n) => n,
-        Err(_) => return Err(ArrayError::new(format!("Failed to create layout from (size: {}, align: {})", size, align)))
+        Err(_) => return Err(ArrayError::new(format!("Failed to create layout from (size: {}, align: {})", total, align)))
     };
+    #[cfg(feature = "failpoints")]
+    if crate::failpoints::take_failure() {
+        return Err(ArrayError::new("Failed to allocate memory for the Array (injected)"));
+    }
     // alloc_zeroed's behaviour is only undefined when trying to
     // allocate zero sized values
     unsafe {
-        let ptr = alloc_zeroed(layout) as *mut T;
+        let ptr = alloc_zeroed(layout);
+        #[cfg(feature = "debug-guard")]
+        let ptr = guard::arm(ptr, size, align);
+        let ptr = ptr as *mut T;
         // Create a NonNull<T> from a *mut T
         // Will fail if the *mut T is null somehow
         if let Some(p) = NonNull::new(ptr) {
-            return Ok(p);
+            // The allocator may reuse a buffer that held removed slots
+            #[cfg(feature = "poison")]
+            crate::poison::forget(ptr as usize);
+            #[cfg(feature = "stats")]
+            crate::stats::record_alloc(size);
+            #[cfg(feature = "tracing")]
+            tracing::trace!(len, bytes = size, "array alloc");
+            Ok(p)
         } else {
-            return Err(ArrayError::new("Failed to allocate memory for the Array"));
+            Err(ArrayError::new("Failed to allocate memory for the Array"))
         }
     }
 }
 
+/// Free space previously allocated by [`alloc`] for an array [T; `len`]
+///
+/// # Safety
+/// `ptr` must have come from `alloc::<T>(len)` with the same `len`, and must
+/// not be used again after this call
+///
+/// With the `debug-guard` feature this panics if the canaries around the
+/// buffer were overwritten
+pub unsafe fn dealloc<T>(ptr: NonNull<T>, len: usize) {
+    // `alloc` already proved this layout valid
+    let size = size_of::<T>() * len;
+    let align = align_of::<T>();
+    #[cfg(not(feature = "debug-guard"))]
+    let (base, total) = (ptr.as_ptr() as *mut u8, size);
+    #[cfg(feature = "debug-guard")]
+    let (base, total) = (
+        guard::disarm(ptr.as_ptr() as *mut u8, size, align),
+        guard::padded_size(size, align).unwrap_unchecked(),
+    );
+    let layout = Layout::from_size_align_unchecked(total, align);
+    free(base, layout);
+    #[cfg(feature = "poison")]
+    crate::poison::forget(ptr.as_ptr() as usize);
+    #[cfg(feature = "stats")]
+    crate::stats::record_dealloc(size);
+    #[cfg(feature = "tracing")]
+    tracing::trace!(len, bytes = size, "array dealloc");
+}
+
 /// Safe, generic implementation of an Array
+///
+/// Not `Copy`: an `Array` owns its allocation and frees it when dropped, so
+/// a bitwise copy would free it twice. Use `clone` for a second array.
+/// Dropping an `Array` frees the buffer but does not drop the elements
+///
+/// Laid out as `#[repr(C)]` with `ptr` followed by `cap`, and this order won't
+/// change. See [`ArrayRawParts`] for passing one across an FFI boundary
+#[repr(C)]
 pub struct Array<T> {
     ptr: NonNull<T>,
     cap: usize,
 }
 
+/// The pointer and length of an [`Array`], with a stable `#[repr(C)]` layout
+/// for handing ownership across dynamic library boundaries
+///
+/// Made by [`Array::into_raw_parts`], turned back into an `Array` by
+/// [`Array::from_raw_parts`]
+#[repr(C)]
+#[derive(Debug)]
+pub struct ArrayRawParts<T> {
+    /// Start of the elements, dangling if the array is zero sized
+    pub ptr: *mut T,
+    /// Number of elements
+    pub cap: usize,
+}
+
+// `Array<T>` owns its elements, like `Vec<T>`
+unsafe impl<T: Send> Send for Array<T> {}
+unsafe impl<T: Sync> Sync for Array<T> {}
+
 // Private Methods
 impl<T> Array<T> {
 
+    /// Whether an array of `len` `T`s occupies no memory at all
+    fn is_zero_sized(len: usize) -> bool {
+        size_of::<T>() == 0 || len == 0
+    }
+
     fn allocate(size: usize) -> Result<NonNull<T>, ArrayError> {
+        if Self::is_zero_sized(size) {
+            // Nothing to allocate, any aligned pointer will do
+            return Ok(NonNull::dangling());
+        }
         alloc::<T>(size)
     }
 
+    /// Free `self.ptr` without dropping `self`
+    unsafe fn deallocate(&mut self) {
+        if !Self::is_zero_sized(self.cap) {
+            dealloc(self.ptr, self.cap);
+        }
+    }
+
+    /// Drop every element in place, as `Drop for Array` only frees the buffer
+    ///
+    /// For types built on an `Array` that keep every slot initialized, to
+    /// call from their own `Drop`
+    ///
+    /// # Safety
+    /// Every element must be initialized, and none may be read or dropped
+    /// again afterwards
+    pub(crate) unsafe fn drop_elements(&mut self) {
+        drop_in_place(self.as_mut_slice());
+    }
+
+    /// Stable partition of `slice`, returning the number of elements matching `pred`
+    fn stable_partition<F>(slice: &mut [T], pred: &mut F) -> usize
+    where F: FnMut(&T) -> bool
+    {
+        match slice.len() {
+            0 => 0,
+            1 => pred(&slice[0]) as usize,
+            len => {
+                let mid = len / 2;
+                let left = Self::stable_partition(&mut slice[..mid], pred);
+                let right = Self::stable_partition(&mut slice[mid..], pred);
+                // [ matched L | rest L | matched R | rest R ] -> swap the middle two
+                slice[left..mid + right].rotate_left(mid - left);
+                left + right
+            }
+        }
+    }
+
     /// copy self.cap items from src to self.ptr    
     unsafe fn copy_from(&self, src: NonNull<T>) {
         use std::ptr::copy;
@@ -72,19 +249,98 @@ impl<T> Array<T> {
         copy(src.as_ptr(), self.as_ptr(), self.cap);
     }
 
+    /// Check that `perm` holds every index in `0..len` exactly once
+    fn check_permutation(perm: &Array<usize>, len: usize) -> Result<(), ArrayError> {
+        if perm.cap != len {
+            return Err(ArrayError::new(format!("length mismatch: {} != {}", perm.cap, len)));
+        }
+        let mut seen = vec![false; len];
+        for &idx in perm.iter() {
+            if idx >= len || seen[idx] {
+                return Err(ArrayError::new(format!("not a permutation: index {} repeated or out of range", idx)));
+            }
+            seen[idx] = true;
+        }
+        Ok(())
+    }
+
+    /// The `k` first elements under `cmp`, in no particular order
+    fn bottom_k_by<F>(&self, k: usize, mut cmp: F) -> Result<Array<T>, ArrayError>
+    where T: Clone, F: FnMut(&T, &T) -> Ordering
+    {
+        if k > self.cap {
+            return Err(ArrayError::new(format!("cannot take {} of {} elements", k, self.cap)));
+        }
+        // A `Vec` drops the clones that don't make the cut
+        let mut scratch = self.as_slice().to_vec();
+        if k > 0 && k < self.cap {
+            scratch.select_nth_unstable_by(k, &mut cmp);
+        }
+        scratch.truncate(k);
+        Ok(scratch.into_iter().collect())
+    }
+
+    /// The distinct elements of `self`, sorted
+    fn sorted_distinct(&self) -> Vec<T>
+    where T: Ord + Clone
+    {
+        let mut distinct = self.as_slice().to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+        distinct
+    }
+
+    /// Merge two sorted, distinct runs, keeping the elements only in `a`,
+    /// in both, or only in `b` as asked
+    fn merge_sorted(a: Vec<T>, b: Vec<T>, only_a: bool, both: bool, only_b: bool) -> Array<T>
+    where T: Ord
+    {
+        let mut merged = Vec::new();
+        let mut a = a.into_iter().peekable();
+        let mut b = b.into_iter().peekable();
+        loop {
+            let order = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) => x.cmp(y),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => break,
+            };
+            match order {
+                Ordering::Less => merged.extend(a.next().filter(|_| only_a)),
+                Ordering::Greater => merged.extend(b.next().filter(|_| only_b)),
+                Ordering::Equal => {
+                    b.next();
+                    merged.extend(a.next().filter(|_| both));
+                }
+            }
+        }
+        merged.into_iter().collect()
+    }
+
     fn in_bounds(&self, idx: usize) -> Option<ArrayError> {
-        if idx > self.cap {
-            return Some(ArrayError::new("index out of range"));
+        #[cfg(feature = "debug-guard")]
+        if let Err(err) = self.check_guards() {
+            return Some(err);
+        }
+        if idx >= self.cap {
+            return Some(ArrayError::new(format!("index {} out of range for length {}", idx, self.cap)));
         }
 
         None
     }
+
+    #[cfg(feature = "poison")]
+    fn is_removed(&self, idx: usize) -> bool {
+        idx < self.cap && crate::poison::is_removed(self.as_ptr() as usize, idx)
+    }
 }
 
 // Public Methods
 impl<T> Array<T> {
     
     /// Create a new Array of size `size`
+    ///
+    /// Arrays of zero size (or of a zero sized `T`) don't allocate
     /// 
     /// Error states:
     ///  * see [`::alloc::alloc`]: ../alloc/fn.alloc.html
@@ -96,6 +352,10 @@ impl<T> Array<T> {
     }
 
     /// Create a new Array, ignoring checks
+    ///
+    /// # Safety
+    /// Panics instead of returning an error, so `size` must be a valid
+    /// allocation size for `T`
     pub unsafe fn new_unchecked(size: usize) -> Self {
         Self::new(size).unwrap()
     }
@@ -110,6 +370,63 @@ impl<T> Array<T> {
         Ok(arr)
     }
 
+    /// Create an Array of exactly `len` elements taken from `iter`
+    ///
+    /// Error states:
+    ///  * see [`::alloc::alloc`]: ../alloc/fn.alloc.html
+    ///  * `iter` yields fewer than `len` elements
+    ///  * `iter` yields more than `len` elements
+    pub fn from_iter_exact<I>(len: usize, iter: I) -> Result<Self, ArrayError>
+    where I: IntoIterator<Item=T>
+    {
+        let arr = Self::new(len)?;
+        let mut iter = iter.into_iter();
+        for offs in 0..len {
+            match iter.next() {
+                // offs < len == arr.cap
+                Some(val) => unsafe { write(arr.as_ptr().add(offs), val) },
+                None => return Err(ArrayError::new(format!("iterator yielded {} elements, expected {}", offs, len))),
+            }
+        }
+        if iter.next().is_some() {
+            return Err(ArrayError::new(format!("iterator yielded more than {} elements", len)));
+        }
+        Ok(arr)
+    }
+
+    /// Create a new Array from every element of `iter`, like `collect` but
+    /// returning an error instead of panicking if memory runs out
+    ///
+    /// Error states:
+    ///  * the elements don't fit in memory
+    ///  * see [`Array::new`]
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, ArrayError>
+    where I: IntoIterator<Item=T>
+    {
+        let mut elems = Vec::new();
+        for val in iter {
+            elems.try_reserve(1).map_err(ArrayError::new)?;
+            elems.push(val);
+        }
+        // Not from_vec, which aborts if shrinking the Vec fails and panics
+        // under `debug-guard` if the copy can't be allocated
+        Self::from_iter_exact(elems.len(), elems)
+    }
+
+    /// Clone `self`, returning an error instead of panicking if the
+    /// allocation fails
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn try_clone(&self) -> Result<Self, ArrayError>
+    where T: Clone
+    {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(len = self.cap, bytes = size_of::<T>() * self.cap, "array clone");
+        // Element by element, a bitwise copy would share anything the elements own
+        Self::from_iter_exact(self.cap, self.iter().cloned())
+    }
+
     /// Fills `self` with `with`
     pub fn fill(&mut self, with: T) where T: Copy {
         for offs in 0..self.cap {
@@ -118,27 +435,84 @@ impl<T> Array<T> {
                 write(self.as_ptr().add(offs), with);
             }
         }
+        #[cfg(feature = "poison")]
+        crate::poison::forget(self.as_ptr() as usize);
     }
 
-    /// Get the value at `idx`
-    /// 
+    /// Replace each element with `f` applied to it, without allocating
+    ///
+    /// Aborts the process if `f` panics, as the slot being rewritten would be
+    /// left holding a value that was moved into `f`
+    pub fn map_in_place<F>(&mut self, mut f: F)
+    where F: FnMut(T) -> T
+    {
+        struct AbortOnUnwind;
+        impl Drop for AbortOnUnwind {
+            fn drop(&mut self) {
+                std::process::abort();
+            }
+        }
+
+        for slot in self.iter_mut() {
+            let guard = AbortOnUnwind;
+            unsafe {
+                // The old value is moved out and the slot overwritten before
+                // anything else can see it
+                write(slot, f(read(slot)));
+            }
+            forget(guard);
+        }
+    }
+
+    /// Call `f` with a mutable reference to each element, in order
+    pub fn for_each_mut<F>(&mut self, f: F)
+    where F: FnMut(&mut T)
+    {
+        self.iter_mut().for_each(f);
+    }
+
+    /// Get a reference to the value at `idx`, or `None` if it's out of range
+    ///
+    /// With the `poison` feature, also `None` if the value was removed by
+    /// [`Array::pop`]
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        #[cfg(feature = "poison")]
+        if self.is_removed(idx) {
+            return None;
+        }
+        self.as_slice().get(idx)
+    }
+
+    /// Get a mutable reference to the value at `idx`, or `None` if it's out of range
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(idx)
+    }
+
+    /// Get a reference to the value at `idx`
+    ///
     /// Error states:
-    ///  * `idx` is greater than the length of the array
-    pub fn get(&self, idx: usize) -> Result<T, ArrayError> {
+    ///  * `idx` is not less than the length of the array
+    ///  * with the `debug-guard` feature, see [`Array::check_guards`]
+    ///  * with the `poison` feature, the value was removed by [`Array::pop`],
+    ///    the error's kind is [`ErrorKind::UseAfterRemove`](crate::error::ErrorKind)
+    pub fn get_checked(&self, idx: usize) -> Result<&T, ArrayError> {
         if let Some(err) = self.in_bounds(idx) {
             return Err(err);
-        } else {
-            unsafe {
-                // This is safe because of (^)
-                Ok(read(self.as_ptr().add(idx)))
-            }
+        }
+        #[cfg(feature = "poison")]
+        if self.is_removed(idx) {
+            return Err(ArrayError::use_after_remove(idx));
+        }
+        unsafe {
+            // This is safe because of (^)
+            Ok(self.get_unchecked(idx))
         }
     }
 
     /// Set the value at `idx` to `val`
     /// 
     /// Error states:
-    ///  * `idx` is greater than the length of the array
+    ///  * `idx` is not less than the length of the array
     pub fn set(&mut self, idx: usize, val: T) -> Result<(), ArrayError> {
         if let Some(err) = self.in_bounds(idx) {
             return Err(err);
@@ -146,22 +520,61 @@ impl<T> Array<T> {
             unsafe {
                 write(self.as_ptr().add(idx), val);
             }
+            #[cfg(feature = "poison")]
+            crate::poison::mark_live(self.as_ptr() as usize, idx);
         }
         Ok(())
     }
 
+    /// Get a reference to the value at `idx` without checking bounds
+    ///
+    /// # Safety
+    /// `idx` must be less than the length of the array
+    pub unsafe fn get_unchecked(&self, idx: usize) -> &T {
+        &*self.as_ptr().add(idx)
+    }
+
+    /// Get a mutable reference to the value at `idx` without checking bounds
+    ///
+    /// # Safety
+    /// `idx` must be less than the length of the array
+    pub unsafe fn get_unchecked_mut(&mut self, idx: usize) -> &mut T {
+        &mut *self.as_ptr().add(idx)
+    }
+
+    /// Set the value at `idx` to `val` without checking bounds
+    ///
+    /// # Safety
+    /// `idx` must be less than the length of the array
+    pub unsafe fn set_unchecked(&mut self, idx: usize, val: T) {
+        write(self.as_ptr().add(idx), val);
+        #[cfg(feature = "poison")]
+        crate::poison::mark_live(self.as_ptr() as usize, idx);
+    }
+
     /// Delete and return the value at `idx`
+    ///
+    /// The slot is zeroed. With the `poison` feature it's filled with
+    /// [`POISON`](crate::poison::POISON) instead, and reading it through
+    /// [`Array::get`] or [`Array::get_checked`] fails until it's set again
     /// 
     /// Error states:
-    ///  * `idx` is greater than the length of the array
+    ///  * `idx` is not less than the length of the array
     pub fn pop(&mut self, idx: usize) -> Result<T, ArrayError> {
         if let Some(err) = self.in_bounds(idx) {
-            return Err(err);
+            Err(err)
         } else {
             unsafe {
                 let addr = self.as_ptr().add(idx);
                 let val: T = read(addr);
+                #[cfg(not(feature = "poison"))]
                 addr.write_bytes(0, 1);
+                #[cfg(feature = "poison")]
+                if size_of::<T>() != 0 {
+                    // Zero sized arrays all share a dangling pointer
+                    crate::poison::poison(addr, 1);
+                    crate::poison::mark_removed(self.as_ptr() as usize, self.cap, idx);
+                }
                 Ok(val)
             }
         }
@@ -170,10 +583,65 @@ impl<T> Array<T> {
     /// Get a pointer to the `Array<T>`
     /// 
     /// Guaranteed to be non-null
-    pub fn as_ptr(&self) -> *mut T {
+    pub const fn as_ptr(&self) -> *mut T {
         self.ptr.as_ptr()
     }
 
+    /// Get a pointer to the `Array<T>` for writing through, like
+    /// [`slice::as_mut_ptr`]
+    ///
+    /// Guaranteed to be non-null
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// The pointers one would get from [`Array::as_ptr`] and one past the last
+    /// element, see [`slice::as_ptr_range`]
+    pub const fn as_ptr_range(&self) -> Range<*const T> {
+        self.as_slice().as_ptr_range()
+    }
+
+    /// Mutable version of [`Array::as_ptr_range`]
+    pub const fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
+        self.as_mut_slice().as_mut_ptr_range()
+    }
+
+    /// View the contents of `self` as a slice
+    pub const fn as_slice(&self) -> &[T] {
+        unsafe {
+            // `self.ptr` is valid for `self.cap` elements
+            from_raw_parts(self.as_ptr(), self.cap)
+        }
+    }
+
+    /// View the contents of `self` as a mutable slice
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            // `self.ptr` is valid for `self.cap` elements, and `&mut self` is unique
+            from_raw_parts_mut(self.as_ptr(), self.cap)
+        }
+    }
+
+    /// Iterate over references to the elements
+    ///
+    /// `slice::Iter::as_slice` views the elements not yet yielded
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Iterate over mutable references to the elements
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Clone the elements into a new `Vec`, allocated once with room for exactly
+    /// `self.cap()` elements
+    pub fn to_vec(&self) -> Vec<T>
+    where T: Clone
+    {
+        self.as_slice().to_vec()
+    }
+
     /// Return the amount of times `val` appears in the `Array`
     pub fn count(&self, val: T) -> usize
     where T: Clone + PartialEq + Display
@@ -181,7 +649,753 @@ impl<T> Array<T> {
         self.clone().into_iter().filter(|v| v == &val).collect::<Vec<T>>().len() 
     }
 
-    pub fn cap(&self) -> usize { self.cap }
+    /// Return the index of the first occurrence of `needle` in the `Array`
+    ///
+    /// An empty `needle` is found at index 0
+    pub fn find_subarray(&self, needle: &[T]) -> Option<usize>
+    where T: PartialEq
+    {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        self.as_slice().windows(needle.len()).position(|w| w == needle)
+    }
+
+    /// Whether the `Array` begins with `prefix`, a slice or another `Array`
+    pub fn starts_with<S>(&self, prefix: S) -> bool
+    where T: PartialEq, S: AsRef<[T]>
+    {
+        self.as_slice().starts_with(prefix.as_ref())
+    }
+
+    /// Whether the `Array` ends with `suffix`, a slice or another `Array`
+    pub fn ends_with<S>(&self, suffix: S) -> bool
+    where T: PartialEq, S: AsRef<[T]>
+    {
+        self.as_slice().ends_with(suffix.as_ref())
+    }
+
+    /// Iterate over the runs of elements separated by elements matching `pred`
+    ///
+    /// Separators are not included, see [`slice::split`]
+    pub fn split<F>(&self, pred: F) -> Split<'_, T, F>
+    where F: FnMut(&T) -> bool
+    {
+        self.as_slice().split(pred)
+    }
+
+    /// Iterate over runs of consecutive elements where `eq` holds for
+    /// every adjacent pair, see [`slice::chunk_by`]
+    pub fn chunk_by<F>(&self, eq: F) -> ChunkBy<'_, T, F>
+    where F: FnMut(&T, &T) -> bool
+    {
+        self.as_slice().chunk_by(eq)
+    }
+
+    /// Iterate over non-overlapping views of `size` elements, the last
+    /// one may be shorter
+    ///
+    /// Error states:
+    ///  * if `size` is 0
+    pub fn chunks(&self, size: usize) -> Result<Chunks<'_, T>, ArrayError> {
+        if size == 0 {
+            return Err(ArrayError::new("chunk size must be greater than 0"));
+        }
+        Ok(self.as_slice().chunks(size))
+    }
+
+    /// Iterate over non-overlapping mutable views of `size` elements, the
+    /// last one may be shorter
+    ///
+    /// The views are disjoint, so each can be handed to its own scoped thread
+    ///
+    /// Error states:
+    ///  * if `size` is 0
+    pub fn chunks_mut(&mut self, size: usize) -> Result<ChunksMut<'_, T>, ArrayError> {
+        if size == 0 {
+            return Err(ArrayError::new("chunk size must be greater than 0"));
+        }
+        Ok(self.as_mut_slice().chunks_mut(size))
+    }
+
+    /// View `self` as fixed size `[T; N]` records, plus the elements left
+    /// over at the end, see [`slice::as_chunks`]
+    ///
+    /// Panics if `N` is 0
+    pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        self.as_slice().as_chunks()
+    }
+
+    /// Mutable version of [`Array::as_chunks`]
+    ///
+    /// Panics if `N` is 0
+    pub fn as_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
+        self.as_mut_slice().as_chunks_mut()
+    }
+
+    /// The first `N` elements as an array, or `None` if there are fewer
+    pub fn first_chunk<const N: usize>(&self) -> Option<&[T; N]> {
+        self.as_slice().first_chunk()
+    }
+
+    /// The last `N` elements as an array, or `None` if there are fewer
+    pub fn last_chunk<const N: usize>(&self) -> Option<&[T; N]> {
+        self.as_slice().last_chunk()
+    }
+
+    /// Exchange the contents of `self` and `other` element by element
+    ///
+    /// Error states:
+    ///  * `self` and `other` have different lengths
+    pub fn swap_with_slice(&mut self, other: &mut [T]) -> Result<(), ArrayError> {
+        if self.cap != other.len() {
+            return Err(ArrayError::new(format!("length mismatch: {} != {}", self.cap, other.len())));
+        }
+        self.as_mut_slice().swap_with_slice(other);
+        Ok(())
+    }
+
+    /// Exchange the contents of `self` and `other`
+    ///
+    /// The two just trade allocations, so this is O(1)
+    ///
+    /// Error states:
+    ///  * `self` and `other` have different lengths
+    pub fn swap_with_array(&mut self, other: &mut Array<T>) -> Result<(), ArrayError> {
+        if self.cap != other.cap {
+            return Err(ArrayError::new(format!("length mismatch: {} != {}", self.cap, other.cap)));
+        }
+        swap(self, other);
+        Ok(())
+    }
+
+    /// Split the `Array` into two new arrays, the elements matching `pred`
+    /// and the elements that don't, each in their original order
+    ///
+    /// `pred` is called once per element, in order
+    pub fn partition<F>(&self, mut pred: F) -> (Array<T>, Array<T>)
+    where T: Clone, F: FnMut(&T) -> bool
+    {
+        let (mut yes, mut no) = (Vec::new(), Vec::new());
+        for val in self.iter() {
+            if pred(val) {
+                yes.push(val.clone());
+            } else {
+                no.push(val.clone());
+            }
+        }
+        (Array::from_vec(yes), Array::from_vec(no))
+    }
+
+    /// Reorder the `Array` so every element matching `pred` comes before every
+    /// element that doesn't, keeping their relative order, and return the
+    /// index of the first element that doesn't match
+    pub fn partition_in_place<F>(&mut self, mut pred: F) -> usize
+    where F: FnMut(&T) -> bool
+    {
+        Self::stable_partition(self.as_mut_slice(), &mut pred)
+    }
+
+    /// Create a new `Array` holding the contents of `self` repeated `n` times
+    ///
+    /// Error states:
+    ///  * the new length overflows
+    ///  * see [`Array::new`]
+    pub fn repeat(&self, n: usize) -> Result<Array<T>, ArrayError>
+    where T: Clone
+    {
+        let len = match self.cap.checked_mul(n) {
+            Some(len) => len,
+            None => return Err(ArrayError::new("Overflow when getting repeated length")),
+        };
+        Self::from_iter_exact(len, self.as_slice().iter().cycle().take(len).cloned())
+    }
+
+    /// Create a new `Array` alternating the elements of `self` and `other`,
+    /// `[a0, b0, a1, b1, ...]`
+    ///
+    /// Error states:
+    ///  * `self` and `other` have different lengths
+    ///  * the combined length overflows
+    ///  * see [`Array::new`]
+    pub fn interleave(&self, other: &Array<T>) -> Result<Array<T>, ArrayError>
+    where T: Clone
+    {
+        if self.cap != other.cap {
+            return Err(ArrayError::new(format!("length mismatch: {} != {}", self.cap, other.cap)));
+        }
+        let len = match self.cap.checked_mul(2) {
+            Some(len) => len,
+            None => return Err(ArrayError::new("Overflow when getting interleaved length")),
+        };
+        let pairs = self.as_slice().iter().zip(other.as_slice());
+        Self::from_iter_exact(len, pairs.flat_map(|(a, b)| [a.clone(), b.clone()]))
+    }
+
+    /// Split `self` into `k` arrays, where array `i` holds every `k`th element
+    /// starting at `i`, undoing [`Array::interleave`] for `k == 2`
+    ///
+    /// Returns a `Vec`, as an `Array` of arrays wouldn't free the inner ones
+    ///
+    /// Error states:
+    ///  * `k` is 0
+    ///  * the length of `self` isn't a multiple of `k`
+    ///  * see [`Array::new`]
+    pub fn deinterleave(&self, k: usize) -> Result<Vec<Array<T>>, ArrayError>
+    where T: Clone
+    {
+        if k == 0 || !self.cap.is_multiple_of(k) {
+            return Err(ArrayError::new(format!("cannot deinterleave {} elements into {} arrays", self.cap, k)));
+        }
+        let len = self.cap / k;
+        (0..k)
+            .map(|i| Self::from_iter_exact(len, self.as_slice().iter().skip(i).step_by(k).cloned()))
+            .collect()
+    }
+
+    /// Create a new `Array` of the elements of `self` where `mask` is true,
+    /// in order
+    ///
+    /// Error states:
+    ///  * `self` and `mask` have different lengths
+    pub fn select(&self, mask: &Array<bool>) -> Result<Array<T>, ArrayError>
+    where T: Clone
+    {
+        if self.cap != mask.cap {
+            return Err(ArrayError::new(format!("length mismatch: {} != {}", self.cap, mask.cap)));
+        }
+        Ok(self.as_slice().iter()
+            .zip(mask.as_slice())
+            .filter(|(_, &keep)| keep)
+            .map(|(val, _)| val.clone())
+            .collect())
+    }
+
+    /// Set every element of `self` where `mask` is true to `value`
+    ///
+    /// Error states:
+    ///  * `self` and `mask` have different lengths
+    pub fn mask_assign(&mut self, mask: &Array<bool>, value: T) -> Result<(), ArrayError>
+    where T: Clone
+    {
+        if self.cap != mask.cap {
+            return Err(ArrayError::new(format!("length mismatch: {} != {}", self.cap, mask.cap)));
+        }
+        for (slot, _) in self.as_mut_slice().iter_mut().zip(mask.as_slice()).filter(|(_, &set)| set) {
+            *slot = value.clone();
+        }
+        Ok(())
+    }
+
+    /// Create a new `Array` where element `i` is `self[idxs[i]]`
+    ///
+    /// Error states:
+    ///  * any index in `idxs` is out of range
+    pub fn gather(&self, idxs: &Array<usize>) -> Result<Array<T>, ArrayError>
+    where T: Clone
+    {
+        if let Some(&bad) = idxs.iter().find(|&&idx| idx >= self.cap) {
+            return Err(ArrayError::new(format!("index {} out of range for length {}", bad, self.cap)));
+        }
+        Ok(idxs.iter().map(|&idx| self.as_slice()[idx].clone()).collect())
+    }
+
+    /// [`Array::gather`], prefetching `self[idxs[i + distance]]` while reading
+    /// `self[idxs[i]]`, to hide memory latency on large, scattered reads
+    ///
+    /// Error states:
+    ///  * any index in `idxs` is out of range
+    pub fn gather_prefetch(&self, idxs: &Array<usize>, distance: usize) -> Result<Array<T>, ArrayError>
+    where T: Clone
+    {
+        if let Some(&bad) = idxs.iter().find(|&&idx| idx >= self.cap) {
+            return Err(ArrayError::new(format!("index {} out of range for length {}", bad, self.cap)));
+        }
+        let mut ahead = idxs.as_slice().get(distance..).unwrap_or(&[]).iter();
+        Ok(idxs.iter().map(|&idx| {
+            if let Some(&next) = ahead.next() {
+                self.prefetch(next);
+            }
+            self.as_slice()[idx].clone()
+        }).collect())
+    }
+
+    /// Hint to the CPU that the element at `idx` will be read soon, pulling
+    /// its cache line in without waiting for it
+    ///
+    /// Only a hint, it does nothing for an out of range `idx` or on targets
+    /// other than x86_64 and aarch64
+    #[inline]
+    pub fn prefetch(&self, idx: usize) {
+        if idx >= self.cap {
+            return;
+        }
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        {
+            // `idx` is in range, and prefetching never faults anyway
+            let addr = unsafe { self.as_ptr().add(idx) } as *const u8;
+            #[cfg(target_arch = "x86_64")]
+            unsafe {
+                std::arch::x86_64::_mm_prefetch(addr as *const i8, std::arch::x86_64::_MM_HINT_T0);
+            }
+            #[cfg(target_arch = "aarch64")]
+            unsafe {
+                std::arch::asm!("prfm pldl1keep, [{}]", in(reg) addr, options(nostack, preserves_flags, readonly));
+            }
+        }
+    }
+
+    /// Write `values[i]` to `self[idxs[i]]`, later writes to the same index win
+    ///
+    /// Every index is checked before anything is written
+    ///
+    /// Error states:
+    ///  * `idxs` and `values` have different lengths
+    ///  * any index in `idxs` is out of range
+    pub fn scatter(&mut self, idxs: &Array<usize>, values: &Array<T>) -> Result<(), ArrayError>
+    where T: Clone
+    {
+        if idxs.cap != values.cap {
+            return Err(ArrayError::new(format!("length mismatch: {} != {}", idxs.cap, values.cap)));
+        }
+        if let Some(&bad) = idxs.iter().find(|&&idx| idx >= self.cap) {
+            return Err(ArrayError::new(format!("index {} out of range for length {}", bad, self.cap)));
+        }
+        let slice = self.as_mut_slice();
+        for (&idx, val) in idxs.iter().zip(values) {
+            slice[idx] = val.clone();
+        }
+        Ok(())
+    }
+
+    /// Create a new `Array` where element `i` is `self[perm[i]]`
+    ///
+    /// Error states:
+    ///  * `perm` is not a permutation of `0..self.cap()`
+    pub fn permute(&self, perm: &Array<usize>) -> Result<Array<T>, ArrayError>
+    where T: Clone
+    {
+        Self::check_permutation(perm, self.cap)?;
+        Ok(perm.iter().map(|&idx| self.as_slice()[idx].clone()).collect())
+    }
+
+    /// Reorder `self` in place so element `i` becomes the old `self[perm[i]]`
+    ///
+    /// Follows each cycle of `perm` with swaps, so nothing is cloned
+    ///
+    /// Error states:
+    ///  * `perm` is not a permutation of `0..self.cap()`
+    pub fn apply_permutation(&mut self, perm: &Array<usize>) -> Result<(), ArrayError> {
+        Self::check_permutation(perm, self.cap)?;
+        let slice = self.as_mut_slice();
+        let mut done = vec![false; slice.len()];
+        for start in 0..slice.len() {
+            let mut cur = start;
+            while !done[cur] {
+                done[cur] = true;
+                let next = perm.as_slice()[cur];
+                if next == start {
+                    break;
+                }
+                // `cur` takes its final value, the one it displaces moves on to `next`
+                slice.swap(cur, next);
+                cur = next;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reorder `self` so element `k` is the one that would be there if
+    /// sorted, everything before it is `<=` and everything after is `>=`,
+    /// see [`slice::select_nth_unstable`]
+    ///
+    /// Error states:
+    ///  * `k` is out of range
+    pub fn select_nth_unstable(&mut self, k: usize) -> Result<(&mut [T], &mut T, &mut [T]), ArrayError>
+    where T: Ord
+    {
+        if let Some(err) = self.in_bounds(k) {
+            return Err(err);
+        }
+        Ok(self.as_mut_slice().select_nth_unstable(k))
+    }
+
+    /// Create a new `Array` of the `k` largest elements, largest first
+    ///
+    /// Error states:
+    ///  * `k` is greater than the length of `self`
+    pub fn top_k(&self, k: usize) -> Result<Array<T>, ArrayError>
+    where T: Ord + Clone
+    {
+        let mut top = self.bottom_k_by(k, |a, b| b.cmp(a))?;
+        top.as_mut_slice().sort_unstable_by(|a, b| b.cmp(a));
+        Ok(top)
+    }
+
+    /// Create a new `Array` of the `k` smallest elements, smallest first
+    ///
+    /// Error states:
+    ///  * `k` is greater than the length of `self`
+    pub fn bottom_k(&self, k: usize) -> Result<Array<T>, ArrayError>
+    where T: Ord + Clone
+    {
+        let mut bottom = self.bottom_k_by(k, T::cmp)?;
+        bottom.as_mut_slice().sort_unstable();
+        Ok(bottom)
+    }
+
+    /// Create a new `Array` of the distinct elements of `self`, sorted
+    pub fn unique(&self) -> Array<T>
+    where T: Ord + Clone
+    {
+        self.sorted_distinct().into_iter().collect()
+    }
+
+    /// Create a new `Array` of the distinct elements of `self`, in the order
+    /// they first appear
+    pub fn unique_hashed(&self) -> Array<T>
+    where T: Hash + Eq + Clone
+    {
+        let mut seen = HashSet::with_capacity(self.cap);
+        self.iter().filter(|val| seen.insert(*val)).cloned().collect()
+    }
+
+    /// Create a new `Array` of the distinct elements in both `self` and `other`, sorted
+    pub fn intersection(&self, other: &Array<T>) -> Array<T>
+    where T: Ord + Clone
+    {
+        Self::merge_sorted(self.sorted_distinct(), other.sorted_distinct(), false, true, false)
+    }
+
+    /// Create a new `Array` of the distinct elements in either `self` or `other`, sorted
+    pub fn union(&self, other: &Array<T>) -> Array<T>
+    where T: Ord + Clone
+    {
+        Self::merge_sorted(self.sorted_distinct(), other.sorted_distinct(), true, true, true)
+    }
+
+    /// Create a new `Array` of the distinct elements in `self` but not in `other`, sorted
+    pub fn difference(&self, other: &Array<T>) -> Array<T>
+    where T: Ord + Clone
+    {
+        Self::merge_sorted(self.sorted_distinct(), other.sorted_distinct(), true, false, false)
+    }
+
+    /// Create a new `Array` of the indices where `self` and `other` differ,
+    /// in ascending order
+    ///
+    /// If the lengths differ, every index past the end of the shorter one
+    /// is included
+    pub fn diff(&self, other: &Array<T>) -> Array<usize>
+    where T: PartialEq
+    {
+        let common = self.cap.min(other.cap);
+        let changed = self.iter().zip(other.iter())
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(idx, _)| idx);
+        changed.chain(common..self.cap.max(other.cap)).collect()
+    }
+
+    /// Create a new `Array` of `f` applied to every window of `window`
+    /// consecutive elements, `self.cap() - window + 1` in all
+    ///
+    /// Error states:
+    ///  * `window` is 0 or greater than the length of `self`
+    pub fn rolling<U, F>(&self, window: usize, f: F) -> Result<Array<U>, ArrayError>
+    where F: FnMut(&[T]) -> U
+    {
+        if window == 0 || window > self.cap {
+            return Err(ArrayError::new(format!("invalid window {} for length {}", window, self.cap)));
+        }
+        Ok(self.as_slice().windows(window).map(f).collect())
+    }
+
+    /// Combine every element into an accumulator, starting from `init`,
+    /// borrowing the elements rather than moving them out
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where F: FnMut(B, &T) -> B
+    {
+        self.iter().fold(init, f)
+    }
+
+    /// Combine every element with `f`, starting from a clone of the first
+    ///
+    /// Only the first element is cloned, returns `None` if `self` is empty
+    pub fn reduce<F>(&self, f: F) -> Option<T>
+    where T: Clone, F: FnMut(T, &T) -> T
+    {
+        let (first, rest) = self.as_slice().split_first()?;
+        Some(rest.iter().fold(first.clone(), f))
+    }
+
+    /// Create a new `Array` of the outputs of `f`, which is called with a
+    /// running state starting at `init` and each element in turn
+    pub fn scan_into<S, U, F>(&self, init: S, mut f: F) -> Array<U>
+    where F: FnMut(&mut S, &T) -> U
+    {
+        let mut state = init;
+        self.iter().map(|v| f(&mut state, v)).collect()
+    }
+
+    pub const fn cap(&self) -> usize { self.cap }
+
+    pub const fn is_empty(&self) -> bool { self.cap == 0 }
+
+    /// The layout of the elements of `self`
+    pub const fn layout(&self) -> ArrayLayout {
+        match ArrayLayout::of::<T>(self.cap) {
+            Some(layout) => layout,
+            // `self` exists, so its size didn't overflow
+            None => unreachable!(),
+        }
+    }
+
+    /// Check the canary bytes around the buffer for out of bounds writes
+    ///
+    /// Error states:
+    ///  * a canary before or after the buffer has been overwritten
+    #[cfg(feature = "debug-guard")]
+    pub fn check_guards(&self) -> Result<(), ArrayError> {
+        if Self::is_zero_sized(self.cap) {
+            return Ok(());
+        }
+        unsafe {
+            // Every non zero sized buffer came from the guarded `alloc`
+            guard::check(self.as_ptr() as *const u8, size_of::<T>() * self.cap, align_of::<T>())
+        }
+    }
+
+    /// Consume the `Array`, leaking its allocation and returning a mutable
+    /// slice over its contents that lives for as long as `'a`
+    ///
+    /// The memory is never freed; dropping the slice does nothing
+    pub fn leak<'a>(self) -> &'a mut [T] where T: 'a {
+        let arr = ManuallyDrop::new(self);
+        unsafe {
+            // `arr` is never dropped, so the allocation outlives any `'a`
+            from_raw_parts_mut(arr.as_ptr(), arr.cap)
+        }
+    }
+
+    /// Give up ownership of the allocation, returning its pointer and length
+    ///
+    /// Nothing is freed until the parts are passed back to
+    /// [`Array::from_raw_parts`]
+    pub fn into_raw_parts(self) -> ArrayRawParts<T> {
+        let arr = ManuallyDrop::new(self);
+        ArrayRawParts { ptr: arr.as_ptr(), cap: arr.cap }
+    }
+
+    /// Take back ownership of an allocation given up by [`Array::into_raw_parts`]
+    ///
+    /// # Safety
+    /// `parts` must have come from `into_raw_parts` on an `Array<T>`, and not
+    /// been used to make an `Array` since. Across a library boundary both
+    /// sides must share the global allocator and be built with the same
+    /// `debug-guard` and `stats` features
+    pub unsafe fn from_raw_parts(parts: ArrayRawParts<T>) -> Self {
+        Array {
+            // Every `Array` holds a non-null pointer
+            ptr: NonNull::new_unchecked(parts.ptr),
+            cap: parts.cap,
+        }
+    }
+
+    /// Convert `self` into a `Box<[T]>` without copying, the box takes
+    /// ownership of the allocation
+    ///
+    /// With the `debug-guard` feature the elements are moved to a new
+    /// allocation instead, as `Box` can't free a guarded one
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        #[cfg(feature = "debug-guard")]
+        if !Self::is_zero_sized(self.cap) {
+            let mut moved = Vec::with_capacity(self.cap);
+            unsafe {
+                // `self` is dropped without dropping its elements
+                copy_nonoverlapping(self.as_ptr(), moved.as_mut_ptr(), self.cap);
+                moved.set_len(self.cap);
+            }
+            return moved.into_boxed_slice();
+        }
+        let arr = ManuallyDrop::new(self);
+        #[cfg(feature = "poison")]
+        crate::poison::forget(arr.as_ptr() as usize);
+        #[cfg(feature = "stats")]
+        if !Self::is_zero_sized(arr.cap) {
+            crate::stats::record_dealloc(size_of::<T>() * arr.cap);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::trace!(len = arr.cap, bytes = size_of::<T>() * arr.cap, "array released to Box");
+        unsafe {
+            // `alloc` and `Box<[T]>` both use the global allocator with
+            // the layout of [T; cap]
+            Box::from_raw(slice_from_raw_parts_mut(arr.as_ptr(), arr.cap))
+        }
+    }
+
+    /// Move the elements of `self` into a `[T; N]`
+    ///
+    /// Hands `self` back unchanged if its length isn't `N`
+    pub fn try_into_array<const N: usize>(self) -> Result<[T; N], Self> {
+        if self.cap != N {
+            return Err(self);
+        }
+        // `self` is dropped without dropping its elements
+        Ok(unsafe { (self.as_ptr() as *const [T; N]).read() })
+    }
+
+    /// Convert `self` into a `Box<[T; N]>`, without copying like
+    /// [`Array::into_boxed_slice`]
+    ///
+    /// Hands `self` back unchanged if its length isn't `N`
+    pub fn try_into_boxed_array<const N: usize>(self) -> Result<Box<[T; N]>, Self> {
+        if self.cap != N {
+            return Err(self);
+        }
+        let boxed = Box::into_raw(self.into_boxed_slice());
+        // A `[T]` of length N has the same layout as `[T; N]`
+        Ok(unsafe { Box::from_raw(boxed as *mut [T; N]) })
+    }
+
+    /// Take ownership of the allocation behind `boxed` without copying
+    ///
+    /// This can't be a `From` impl, it would overlap with `From<Iterator>`
+    ///
+    /// With the `debug-guard` feature the elements are moved to a new,
+    /// guarded allocation instead
+    pub fn from_boxed_slice(boxed: Box<[T]>) -> Self {
+        #[cfg(feature = "debug-guard")]
+        if !Self::is_zero_sized(boxed.len()) {
+            let arr = match Self::new(boxed.len()) {
+                Ok(a) => a,
+                Err(e) => panic!("Encountered an error when moving a boxed slice -> {}", e.msg()),
+            };
+            unsafe {
+                copy_nonoverlapping(boxed.as_ptr(), arr.as_ptr(), arr.cap);
+                // Free the box without dropping the elements that moved out of it
+                drop(Box::from_raw(Box::into_raw(boxed) as *mut [ManuallyDrop<T>]));
+            }
+            return arr;
+        }
+        let cap = boxed.len();
+        #[cfg(feature = "stats")]
+        if !Self::is_zero_sized(cap) {
+            crate::stats::record_alloc(size_of::<T>() * cap);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::trace!(len = cap, bytes = size_of::<T>() * cap, "array adopted from Box");
+        let ptr = Box::into_raw(boxed) as *mut T;
+        #[cfg(feature = "poison")]
+        crate::poison::forget(ptr as usize);
+        Array {
+            // Box never holds a null pointer
+            ptr: unsafe { NonNull::new_unchecked(ptr) },
+            cap,
+        }
+    }
+
+    /// Take ownership of the buffer behind `vec` without copying
+    ///
+    /// The elements are copied when `vec` has spare capacity, as the
+    /// allocator may move the buffer while shrinking it, and under the
+    /// `debug-guard` feature, see [`Array::from_boxed_slice`]
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        Self::from_boxed_slice(vec.into_boxed_slice())
+    }
+
+    /// Reinterpret `self` as an `Array<U>`, reusing the allocation
+    ///
+    /// Error states:
+    ///  * `size_of::<U>() != size_of::<T>()`
+    ///  * `align_of::<U>() != align_of::<T>()`
+    ///
+    /// # Safety
+    /// Every element of `self` must also be a valid `U`
+    pub unsafe fn try_cast<U>(self) -> Result<Array<U>, ArrayError> {
+        if size_of::<U>() != size_of::<T>() || align_of::<U>() != align_of::<T>() {
+            return Err(ArrayError::new(format!(
+                "cannot cast (size: {}, align: {}) to (size: {}, align: {})",
+                size_of::<T>(), align_of::<T>(), size_of::<U>(), align_of::<U>()
+            )));
+        }
+        let arr = ManuallyDrop::new(self);
+        // Same size and align, so the layout of [U; cap] is the layout of [T; cap]
+        Ok(Array {
+            ptr: arr.ptr.cast::<U>(),
+            cap: arr.cap,
+        })
+    }
+
+    /// Reinterpret `self` as an `Array<U>`, reusing the allocation
+    ///
+    /// Panics if the layouts of `T` and `U` differ, see [`Array::try_cast`]
+    ///
+    /// # Safety
+    /// Every element of `self` must also be a valid `U`
+    pub unsafe fn cast<U>(self) -> Array<U> {
+        match self.try_cast() {
+            Ok(arr) => arr,
+            Err(e) => panic!("{}", e.msg()),
+        }
+    }
+}
+
+impl<T> Array<Array<T>> {
+
+    /// Move the elements of every inner `Array` into one new `Array`, in order
+    ///
+    /// Error states:
+    ///  * the total length overflows
+    ///  * see [`Array::new`]
+    pub fn flatten(self) -> Result<Array<T>, ArrayError> {
+        let total = self.as_slice().iter().try_fold(0usize, |acc, inner| acc.checked_add(inner.cap));
+        let total = match total {
+            Some(n) => n,
+            None => return Err(ArrayError::new("Overflow when getting flattened length")),
+        };
+        let out = Array::<T>::new(total)?;
+        let mut offs = 0;
+        for idx in 0..self.cap {
+            unsafe {
+                // Take the inner array out of `self`, which never drops its elements
+                let inner = read(self.as_ptr().add(idx));
+                // The inner elements move into `out`, so only the inner buffer is freed
+                copy_nonoverlapping(inner.as_ptr(), out.as_ptr().add(offs), inner.cap);
+                offs += inner.cap;
+            }
+        }
+        Ok(out)
+    }
+}
+
+// Text conversions
+impl Array<u8> {
+
+    /// Convert `self` into a `String` without copying
+    ///
+    /// Error states:
+    ///  * `self` is not valid UTF-8
+    pub fn from_utf8(self) -> Result<String, ArrayError> {
+        String::from_utf8(self.into_boxed_slice().into_vec())
+            .map_err(|e| ArrayError::new(e.utf8_error()))
+    }
+
+    /// Compare `self` and `other` in time that depends only on their length,
+    /// never on where they differ
+    ///
+    /// Arrays of different lengths compare unequal immediately
+    pub fn ct_eq(&self, other: &Array<u8>) -> bool {
+        if self.cap != other.cap {
+            return false;
+        }
+        let mut diff = 0u8;
+        for (a, b) in self.as_slice().iter().zip(other.as_slice()) {
+            // black_box keeps the compiler from turning this into an early exit
+            diff = black_box(diff | (a ^ b));
+        }
+        diff == 0
+    }
 }
 
 // Trait implemetations
@@ -192,13 +1406,42 @@ impl<T: Debug + Copy> Debug for Array<T> {
     }
 }
 
-impl<T: PartialEq + Clone> PartialEq for Array<T> {
+impl<T: PartialEq> PartialEq for Array<T> {
     fn eq(&self, other: &Self) -> bool {
-        let items = self.clone().into_iter().zip(other.clone().into_iter());
-        for (s, o) in items {
-            if s != o { return false }
-        }
-        true
+        // Must agree with `[T]` for `Borrow<[T]>`
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for Array<T> {}
+
+impl<T: Hash> Hash for Array<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T> AsRef<[T]> for Array<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsMut<[T]> for Array<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> Borrow<[T]> for Array<T> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> BorrowMut<[T]> for Array<T> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
     }
 }
 
@@ -206,37 +1449,79 @@ impl<T, U> From<U> for Array<T>
 where U: Iterator<Item=T>
 {
     fn from(i: U) -> Self {
-        let v: Vec<T> = i.collect();
-        let arr: Array<T> = Self::new(v.len()).expect("");
-        let vec_ptr: NonNull<T> = NonNull::new(v.as_ptr() as *mut T).expect("Vec.as_ptr() returned a null pointer");
-        unsafe {
-            // This should be safe, assuming the Vec allocated correctly
-            // arr.cap == vec.len() && !vec_ptr.is_null()
-            arr.copy_from(vec_ptr);
-        }
-        arr
+        Self::from_vec(i.collect())
     }
 }
 
 impl<T: Clone> Clone for Array<T> {
     fn clone(&self) -> Self {
-        // Create a new array
-        let arr = match Self::new(self.cap) {
-            // Get the array
+        match self.try_clone() {
             Ok(a) => a,
-            Err(e) => {
-                panic!("Encountered an error when cloning -> {}", e.msg())
-            }
-        };
+            Err(e) => panic!("Encountered an error when cloning -> {}", e.msg()),
+        }
+    }
+}
+
+// Through std's `FromIterator<Result<A, E>> for Result<V, E>` this also
+// collects iterators of `Result`s, stopping at the first error
+impl<T> FromIterator<T> for Array<T> {
+    fn from_iter<I: IntoIterator<Item=T>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T> From<Array<T>> for Box<[T]> {
+    fn from(arr: Array<T>) -> Self {
+        arr.into_boxed_slice()
+    }
+}
+
+// `From<&str>` would overlap with `From<Iterator>`, so strings parse instead
+impl FromStr for Array<u8> {
+    type Err = ArrayError;
+
+    fn from_str(s: &str) -> Result<Self, ArrayError> {
+        Ok(Self::from_boxed_slice(s.as_bytes().into()))
+    }
+}
+
+impl FromStr for Array<char> {
+    type Err = ArrayError;
+
+    fn from_str(s: &str) -> Result<Self, ArrayError> {
+        Self::from_iter_exact(s.chars().count(), s.chars())
+    }
+}
+
+impl From<Array<char>> for String {
+    fn from(arr: Array<char>) -> Self {
+        arr.as_slice().iter().collect()
+    }
+}
+
+impl<T> Drop for Array<T> {
+    fn drop(&mut self) {
         unsafe {
-            // copies `arr.cap` values from `self.ptr`
-            arr.copy_from(self.ptr);
+            // `self.ptr` holds the layout of [T; self.cap] from the global allocator
+            self.deallocate();
         }
-        arr
     }
 }
 
-impl<T: Copy + Clone> Copy for Array<T> {}
+// Panics when out of range, like indexing a slice
+impl<T, I: SliceIndex<[T]>> Index<I> for Array<T> {
+    type Output = I::Output;
+
+    fn index(&self, idx: I) -> &I::Output {
+        &self.as_slice()[idx]
+    }
+}
+
+impl<T, I: SliceIndex<[T]>> IndexMut<I> for Array<T> {
+    fn index_mut(&mut self, idx: I) -> &mut I::Output {
+        &mut self.as_mut_slice()[idx]
+    }
+}
 
 impl<T> IntoIterator for Array<T> {
     type IntoIter = ArrayIter<T>;
@@ -247,6 +1532,24 @@ impl<T> IntoIterator for Array<T> {
     }
 }
 
+impl<'a, T> IntoIterator for &'a Array<T> {
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Array<T> {
+    type IntoIter = IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
 /// Iterator for `Array<T>`
 pub struct ArrayIter<T> {
     arr: Array<T>,
@@ -256,6 +1559,11 @@ pub struct ArrayIter<T> {
     fn new(arr: Array<T>) -> Self  {
         ArrayIter { arr, idx: 0 }
     }
+
+    /// View the elements not yet yielded
+    pub fn as_slice(&self) -> &[T] {
+        &self.arr.as_slice()[self.idx..]
+    }
 } impl<T> Iterator for ArrayIter<T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
@@ -263,8 +1571,33 @@ pub struct ArrayIter<T> {
             None
         } else {
             self.idx += 1;
-            // This unwrap is guaranteed safe (idx will be less than cap)
-            Some(self.arr.get(self.idx - 1).unwrap())
+            unsafe {
+                // idx - 1 is less than cap, and is never read again
+                Some(read(self.arr.as_ptr().add(self.idx - 1)))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.arr.cap - self.idx;
+        (remaining, Some(remaining))
+    }
+
+    // `skip` is built on `nth`, so this makes it O(1) as well
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.idx = self.idx.saturating_add(n).min(self.arr.cap);
+        self.next()
+    }
+
+    fn count(self) -> usize {
+        self.arr.cap - self.idx
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        if self.idx == self.arr.cap {
+            return None;
         }
+        self.idx = self.arr.cap - 1;
+        self.next()
     }
-}
\ No newline at end of file
+} impl<T> ExactSizeIterator for ArrayIter<T> {}
\ No newline at end of file
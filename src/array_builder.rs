@@ -0,0 +1,106 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        mem::{ ManuallyDrop, MaybeUninit },
+        ptr,
+    },
+};
+
+/// Fills an `Array` one element at a time, tracking how many slots have
+/// been written so none is read before it's initialized
+///
+/// Elements already pushed are dropped if the builder is dropped unfinished
+pub struct ArrayBuilder<T> {
+    slots: Array<MaybeUninit<T>>,
+    // Slots `..len` are initialized
+    len: usize,
+} impl<T> ArrayBuilder<T> {
+    /// Create a new, empty ArrayBuilder with room for `cap` elements
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(cap: usize) -> Result<Self, ArrayError> {
+        Ok(ArrayBuilder { slots: Array::new(cap)?, len: 0 })
+    }
+
+    /// Write `val` to the next free slot, handing it back if every slot is full
+    pub fn push(&mut self, val: T) -> Result<(), T> {
+        match self.slots.get_mut(self.len) {
+            Some(slot) => {
+                slot.write(val);
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(val),
+        }
+    }
+
+    /// The number of elements pushed so far
+    pub fn len(&self) -> usize { self.len }
+
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// The number of slots the finished `Array` will have
+    pub fn cap(&self) -> usize { self.slots.cap() }
+
+    /// The number of slots left to fill
+    pub fn remaining(&self) -> usize { self.slots.cap() - self.len }
+
+    pub fn is_full(&self) -> bool { self.remaining() == 0 }
+
+    /// The elements pushed so far
+    pub fn as_slice(&self) -> &[T] {
+        // The first `len` slots are initialized
+        unsafe { &*(&self.slots.as_slice()[..self.len] as *const [MaybeUninit<T>] as *const [T]) }
+    }
+
+    /// Turn the builder into an `Array`, reusing its allocation
+    ///
+    /// Hands the builder back unchanged if any slot has not been written
+    pub fn finish(self) -> Result<Array<T>, Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+        // Every slot is initialized, and MaybeUninit<T> has the same layout as T
+        Ok(unsafe { self.into_slots().cast() })
+    }
+
+    /// Move the elements pushed so far into a new `Array` of just that length
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn finish_prefix(self) -> Result<Array<T>, ArrayError> {
+        // A full builder is reused as is, otherwise `finish` hands it back
+        let this = match self.finish() {
+            Ok(arr) => return Ok(arr),
+            Err(this) => this,
+        };
+        // Dropping `this` on failure drops the elements
+        let mut arr = Array::<MaybeUninit<T>>::new(this.len)?;
+        let len = this.len;
+        let slots = this.into_slots();
+        unsafe {
+            ptr::copy_nonoverlapping(slots.as_ptr(), arr.as_mut_slice().as_mut_ptr(), len);
+            // Every slot of `arr` is now initialized
+            Ok(arr.cast())
+        }
+    }
+
+    /// Take the slots without running `Drop`
+    fn into_slots(self) -> Array<MaybeUninit<T>> {
+        let this = ManuallyDrop::new(self);
+        // `this` is never used again
+        unsafe { ptr::read(&this.slots) }
+    }
+}
+
+impl<T> Drop for ArrayBuilder<T> {
+    fn drop(&mut self) {
+        for slot in &mut self.slots.as_mut_slice()[..self.len] {
+            unsafe { slot.assume_init_drop() };
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::cell::Cell,
+};
+
+/// An `Array` whose slots can each be read and written through `&self`,
+/// like a `Cell` per slot
+///
+/// Not `Sync`, so shared mutation stays on one thread
+pub struct ArrayCell<T: Copy> {
+    cells: Array<Cell<T>>,
+} impl<T: Copy> ArrayCell<T> {
+    /// Create a new ArrayCell of `len` copies of `val`
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(len: usize, val: T) -> Result<Self, ArrayError> {
+        Ok(ArrayCell { cells: Array::from_iter_exact(len, (0..len).map(|_| Cell::new(val)))? })
+    }
+
+    /// Wrap the elements of `arr`, without copying
+    pub fn from_array(arr: Array<T>) -> Self {
+        ArrayCell {
+            // Cell<T> has the same layout as T
+            cells: unsafe { arr.cast() },
+        }
+    }
+
+    /// Get the value at `idx`, or `None` if it's out of range
+    pub fn get(&self, idx: usize) -> Option<T> {
+        self.cells.get(idx).map(Cell::get)
+    }
+
+    /// Set the value at `idx` to `val`
+    ///
+    /// Error states:
+    ///  * `idx` is not less than the length of the array
+    pub fn set(&self, idx: usize, val: T) -> Result<(), ArrayError> {
+        self.cells.get_checked(idx)?.set(val);
+        Ok(())
+    }
+
+    /// Replace the value at `idx` with `f` applied to it, returning the new value
+    ///
+    /// Error states:
+    ///  * `idx` is not less than the length of the array
+    pub fn update<F>(&self, idx: usize, f: F) -> Result<T, ArrayError>
+    where F: FnOnce(T) -> T
+    {
+        let cell = self.cells.get_checked(idx)?;
+        cell.set(f(cell.get()));
+        Ok(cell.get())
+    }
+
+    /// Swap the values at `a` and `b`
+    ///
+    /// Error states:
+    ///  * `a` or `b` is not less than the length of the array
+    pub fn swap(&self, a: usize, b: usize) -> Result<(), ArrayError> {
+        self.cells.get_checked(a)?.swap(self.cells.get_checked(b)?);
+        Ok(())
+    }
+
+    /// View the slots as a slice of cells, e.g. to hand out a sub-range
+    pub fn as_slice_of_cells(&self) -> &[Cell<T>] {
+        self.cells.as_slice()
+    }
+
+    pub fn len(&self) -> usize { self.cells.cap() }
+
+    pub fn is_empty(&self) -> bool { self.cells.cap() == 0 }
+
+    /// Unwrap the underlying `Array`
+    pub fn into_array(self) -> Array<T> {
+        // Cell<T> has the same layout as T
+        unsafe { self.cells.cast() }
+    }
+}
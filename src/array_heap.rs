@@ -0,0 +1,116 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::mem::MaybeUninit,
+};
+
+/// A max-heap priority queue holding at most a fixed number of elements,
+/// allocated once up front
+///
+/// Elements still in the heap are dropped with it
+pub struct ArrayHeap<T: Ord> {
+    slots: Array<MaybeUninit<T>>,
+    // Slots `..len` are initialized and ordered as a binary heap
+    len: usize,
+} impl<T: Ord> ArrayHeap<T> {
+    /// Create a new, empty ArrayHeap with room for `cap` elements
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(cap: usize) -> Result<Self, ArrayError> {
+        Ok(ArrayHeap { slots: Array::new(cap)?, len: 0 })
+    }
+
+    /// Add `val` to the heap, handing it back if the heap is full
+    pub fn push(&mut self, val: T) -> Result<(), T> {
+        match self.slots.get_mut(self.len) {
+            Some(slot) => {
+                slot.write(val);
+                self.len += 1;
+                self.sift_up(self.len - 1);
+                Ok(())
+            }
+            None => Err(val),
+        }
+    }
+
+    /// Remove and return the greatest element
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.slots.as_mut_slice().swap(0, self.len);
+        // The slot at `len` was initialized, and is now outside the heap
+        let top = unsafe { self.slots.as_slice()[self.len].assume_init_read() };
+        self.sift_down(0);
+        Some(top)
+    }
+
+    /// The greatest element, without removing it
+    pub fn peek(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    /// The elements in heap order, which is not sorted order
+    pub fn as_slice(&self) -> &[T] {
+        // The first `len` slots are initialized
+        unsafe { &*(&self.slots.as_slice()[..self.len] as *const [MaybeUninit<T>] as *const [T]) }
+    }
+
+    pub fn len(&self) -> usize { self.len }
+
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// The most elements the heap can hold
+    pub fn cap(&self) -> usize { self.slots.cap() }
+
+    pub fn is_full(&self) -> bool { self.len == self.slots.cap() }
+
+    /// Drop every element
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+
+    fn heap_mut(&mut self) -> &mut [T] {
+        // The first `len` slots are initialized
+        unsafe { &mut *(&mut self.slots.as_mut_slice()[..self.len] as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+
+    fn sift_up(&mut self, mut idx: usize) {
+        let heap = self.heap_mut();
+        while idx > 0 {
+            let parent = (idx - 1) / 2;
+            if heap[idx] <= heap[parent] {
+                break;
+            }
+            heap.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut idx: usize) {
+        let heap = self.heap_mut();
+        loop {
+            let mut largest = idx;
+            for child in [2 * idx + 1, 2 * idx + 2] {
+                if child < heap.len() && heap[child] > heap[largest] {
+                    largest = child;
+                }
+            }
+            if largest == idx {
+                break;
+            }
+            heap.swap(idx, largest);
+            idx = largest;
+        }
+    }
+}
+
+impl<T: Ord> Drop for ArrayHeap<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
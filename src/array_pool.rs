@@ -0,0 +1,54 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::collections::HashMap,
+};
+
+/// Recycles the allocations of `Array`s by length, so repeatedly making
+/// and freeing arrays of the same sizes doesn't go to the allocator
+pub struct ArrayPool<T> {
+    // Arrays given back, by length
+    free: HashMap<usize, Vec<Array<T>>>,
+    max_per_len: usize,
+} impl<T> ArrayPool<T> {
+    /// Create a new, empty ArrayPool keeping at most `max_per_len` arrays
+    /// of each length
+    pub fn new(max_per_len: usize) -> Self {
+        ArrayPool { free: HashMap::new(), max_per_len }
+    }
+
+    /// Take an `Array` of `len` elements, reusing one given back if there is one
+    ///
+    /// A fresh `Array` is zeroed, a reused one still holds what it did when
+    /// it was given back
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn take(&mut self, len: usize) -> Result<Array<T>, ArrayError> {
+        match self.free.get_mut(&len).and_then(Vec::pop) {
+            Some(arr) => Ok(arr),
+            None => Array::new(len),
+        }
+    }
+
+    /// Hand `arr` back for a later [`ArrayPool::take`] of the same length,
+    /// it's freed instead if the pool already holds `max_per_len` of them
+    pub fn give_back(&mut self, arr: Array<T>) {
+        let bucket = self.free.entry(arr.cap()).or_default();
+        if bucket.len() < self.max_per_len {
+            bucket.push(arr);
+        }
+    }
+
+    /// The number of arrays waiting to be reused
+    pub fn retained(&self) -> usize {
+        self.free.values().map(Vec::len).sum()
+    }
+
+    /// Free every retained array
+    pub fn clear(&mut self) {
+        self.free.clear();
+    }
+}
@@ -0,0 +1,117 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        fmt,
+        ops::Deref,
+        str::from_utf8_unchecked,
+    },
+};
+
+/// A UTF-8 string with a fixed capacity in bytes, backed by an `Array<u8>`
+pub struct ArrayString {
+    buf: Array<u8>,
+    len: usize,
+} impl ArrayString {
+    /// Create a new, empty ArrayString with room for `cap` bytes
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(cap: usize) -> Result<Self, ArrayError> {
+        Ok(ArrayString { buf: Array::new(cap)?, len: 0 })
+    }
+
+    fn capacity_error(&self, needed: usize) -> ArrayError {
+        ArrayError::new(format!("capacity full: {} more bytes needed, {} left", needed, self.remaining()))
+    }
+
+    /// Append `c`
+    ///
+    /// Error states:
+    ///  * there isn't room for the encoded `c`, `self` is unchanged
+    pub fn push(&mut self, c: char) -> Result<(), ArrayError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Append all of `s`
+    ///
+    /// Error states:
+    ///  * there isn't room for `s`, `self` is unchanged
+    pub fn push_str(&mut self, s: &str) -> Result<(), ArrayError> {
+        if s.len() > self.remaining() {
+            return Err(self.capacity_error(s.len()));
+        }
+        let end = self.len + s.len();
+        self.buf.as_mut_slice()[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+
+    /// Append as much of `s` as fits, cutting it on a char boundary
+    ///
+    /// Unlike [`ArrayString::push_str`] this is not all or nothing, an error
+    /// means `s` was cut short and the longest prefix that fit has already
+    /// been appended. Use `push_str` to leave `self` unchanged instead
+    ///
+    /// Error states:
+    ///  * `s` didn't fit completely, the part that fit is still appended
+    pub fn try_push(&mut self, s: &str) -> Result<(), ArrayError> {
+        let mut fits = s.len().min(self.remaining());
+        while !s.is_char_boundary(fits) {
+            fits -= 1;
+        }
+        let err = if fits < s.len() { Some(self.capacity_error(s.len())) } else { None };
+        // `fits` is a char boundary that fits
+        self.push_str(&s[..fits])?;
+        match err {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// View the contents as a `str`
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            // Only whole `str`s are ever copied in
+            from_utf8_unchecked(&self.buf.as_slice()[..self.len])
+        }
+    }
+
+    /// Bytes that can still be pushed
+    pub fn remaining(&self) -> usize { self.buf.cap() - self.len }
+
+    pub fn cap(&self) -> usize { self.buf.cap() }
+
+    /// Remove the contents, keeping the buffer
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl Deref for ArrayString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ArrayString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for ArrayString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl fmt::Write for ArrayString {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
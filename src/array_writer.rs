@@ -0,0 +1,66 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::io::{ self, ErrorKind, Write },
+};
+
+/// An `io::Write` sink filling a fixed size `Array<u8>` from the front
+///
+/// Writes past the end fail with `ErrorKind::WriteZero`
+pub struct ArrayWriter {
+    buf: Array<u8>,
+    written: usize,
+} impl ArrayWriter {
+    /// Create a new ArrayWriter over a zeroed `Array` of `cap` bytes
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(cap: usize) -> Result<Self, ArrayError> {
+        Ok(Self::from_array(Array::new(cap)?))
+    }
+
+    /// Write into `buf`, starting from its first byte
+    pub fn from_array(buf: Array<u8>) -> Self {
+        ArrayWriter { buf, written: 0 }
+    }
+
+    /// The number of bytes written so far
+    pub fn written(&self) -> usize { self.written }
+
+    /// The number of bytes that can still be written
+    pub fn remaining(&self) -> usize { self.buf.cap() - self.written }
+
+    /// The bytes written so far
+    pub fn as_written(&self) -> &[u8] {
+        &self.buf.as_slice()[..self.written]
+    }
+
+    /// Start writing from the front again, without clearing the buffer
+    pub fn reset(&mut self) {
+        self.written = 0;
+    }
+
+    /// Unwrap the underlying `Array`, bytes past [`ArrayWriter::written`]
+    /// hold whatever they did before
+    pub fn into_inner(self) -> Array<u8> {
+        self.buf
+    }
+}
+
+impl Write for ArrayWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if !buf.is_empty() && self.remaining() == 0 {
+            return Err(io::Error::new(ErrorKind::WriteZero, "ArrayWriter is full"));
+        }
+        let n = buf.len().min(self.remaining());
+        self.buf.as_mut_slice()[self.written..self.written + n].copy_from_slice(&buf[..n]);
+        self.written += n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use {
+    crate::{
+        array::Array,
+        pod::Pod,
+    },
+    std::io,
+    tokio::io::{ AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt },
+};
+
+// Async I/O straight into and out of the buffer, under the `tokio` feature
+impl<T: Pod> Array<T> {
+
+    /// Fill `self` with exactly as many bytes as it holds from `reader`
+    ///
+    /// Fails with `ErrorKind::UnexpectedEof` if `reader` ends first, leaving
+    /// `self` partly overwritten
+    pub async fn read_exact_from<R>(&mut self, reader: &mut R) -> io::Result<()>
+    where R: AsyncRead + Unpin + ?Sized
+    {
+        reader.read_exact(self.as_bytes_mut()).await.map(|_| ())
+    }
+
+    /// Write every byte of `self` to `writer`
+    pub async fn write_all_to<W>(&self, writer: &mut W) -> io::Result<()>
+    where W: AsyncWrite + Unpin + ?Sized
+    {
+        writer.write_all(self.as_bytes()).await
+    }
+}
@@ -0,0 +1,114 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        collections::hash_map::DefaultHasher,
+        f64::consts::LN_2,
+        hash::{ Hash, Hasher },
+        marker::PhantomData,
+    },
+};
+
+/// A set membership sketch in a fixed number of bits, packed into an
+/// `Array<u64>`
+///
+/// `contains` never misses an inserted value, but may report values that
+/// were never inserted
+pub struct BloomFilter<T: ?Sized> {
+    words: Array<u64>,
+    bits: usize,
+    hashes: u32,
+    _marker: PhantomData<fn(&T)>,
+} impl<T: Hash + ?Sized> BloomFilter<T> {
+    /// Create a new, empty BloomFilter sized so that after `expected_items`
+    /// inserts `contains` gives false positives at about `fp_rate`
+    ///
+    /// Error states:
+    ///  * `expected_items` is 0
+    ///  * `fp_rate` is not strictly between 0 and 1
+    ///  * see [`Array::new`]
+    pub fn new(expected_items: usize, fp_rate: f64) -> Result<Self, ArrayError> {
+        if expected_items == 0 {
+            return Err(ArrayError::new("A bloom filter must expect at least one item"));
+        }
+        if !(fp_rate > 0.0 && fp_rate < 1.0) {
+            return Err(ArrayError::new(format!("False positive rate {} is not between 0 and 1", fp_rate)));
+        }
+        let n = expected_items as f64;
+        let bits = (-n * fp_rate.ln() / (LN_2 * LN_2)).ceil().max(1.0) as usize;
+        let hashes = ((bits as f64 / n) * LN_2).round().max(1.0) as u32;
+        Self::with_params(bits, hashes)
+    }
+
+    /// Create a new, empty BloomFilter of `bits` bits setting `hashes` bits per item
+    ///
+    /// Error states:
+    ///  * `bits` or `hashes` is 0
+    ///  * see [`Array::new`]
+    pub fn with_params(bits: usize, hashes: u32) -> Result<Self, ArrayError> {
+        if bits == 0 || hashes == 0 {
+            return Err(ArrayError::new("A bloom filter needs at least one bit and one hash"));
+        }
+        Ok(BloomFilter {
+            words: Array::new(bits.div_ceil(64))?,
+            bits,
+            hashes,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Record `item` as present
+    pub fn insert(&mut self, item: &T) {
+        for bit in self.bit_indices(item) {
+            self.words[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Whether `item` may have been inserted, `false` means it definitely wasn't
+    pub fn contains(&self, item: &T) -> bool {
+        self.bit_indices(item).all(|bit| self.words[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Add every item recorded in `other` to `self`
+    ///
+    /// Error states:
+    ///  * `other` has a different number of bits or hashes
+    pub fn merge(&mut self, other: &Self) -> Result<(), ArrayError> {
+        if self.bits != other.bits || self.hashes != other.hashes {
+            return Err(ArrayError::new(format!(
+                "Cannot merge a bloom filter of {} bits and {} hashes into one of {} bits and {} hashes",
+                other.bits, other.hashes, self.bits, self.hashes
+            )));
+        }
+        for (word, theirs) in self.words.iter_mut().zip(other.words.iter()) {
+            *word |= theirs;
+        }
+        Ok(())
+    }
+
+    /// Forget every item
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// The number of bits in the filter
+    pub fn bits(&self) -> usize { self.bits }
+
+    /// The number of bits set per item
+    pub fn hashes(&self) -> u32 { self.hashes }
+
+    // Double hashing, bit i is h1 + i * h2
+    fn bit_indices(&self, item: &T) -> impl Iterator<Item = usize> {
+        let hash = |seed: u64| {
+            let mut hasher = DefaultHasher::new();
+            seed.hash(&mut hasher);
+            item.hash(&mut hasher);
+            hasher.finish()
+        };
+        let (h1, h2) = (hash(0), hash(1) | 1);
+        let bits = self.bits as u64;
+        (0..self.hashes as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % bits) as usize)
+    }
+}
@@ -0,0 +1,90 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        mem::replace,
+        ops::Range,
+    },
+};
+
+/// An `Array` whose every operation returns a `Result` or `Option` instead
+/// of panicking, for code where a panic is a denial of service
+///
+/// It deliberately has no `Index`, `Clone` or `From<Iterator>` impls, use
+/// [`CheckedArray::get`], [`CheckedArray::try_clone`] and
+/// [`CheckedArray::try_from_iter`] instead. Building the inner `Array`
+/// through its `FromIterator` or [`Array::from_vec`] impls can still panic on
+/// a failed allocation, so those aren't covered by [`CheckedArray::from_array`]
+pub struct CheckedArray<T> {
+    inner: Array<T>,
+} impl<T> CheckedArray<T> {
+    /// Create a new, zeroed CheckedArray of `len` elements
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(len: usize) -> Result<Self, ArrayError> {
+        Ok(CheckedArray { inner: Array::new(len)? })
+    }
+
+    /// Create a new CheckedArray from every element of `iter`
+    ///
+    /// Error states:
+    ///  * see [`Array::try_from_iter`]
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, ArrayError>
+    where I: IntoIterator<Item=T>
+    {
+        Ok(CheckedArray { inner: Array::try_from_iter(iter)? })
+    }
+
+    /// Wrap `arr`, without copying
+    pub fn from_array(arr: Array<T>) -> Self {
+        CheckedArray { inner: arr }
+    }
+
+    /// Get a reference to the value at `idx`, or `None` if it's out of range
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.inner.get(idx)
+    }
+
+    /// Get a mutable reference to the value at `idx`, or `None` if it's out of range
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        self.inner.get_mut(idx)
+    }
+
+    /// Set the value at `idx` to `val`, returning the old value
+    ///
+    /// Error states:
+    ///  * `idx` is not less than the length of the array
+    pub fn replace(&mut self, idx: usize, val: T) -> Result<T, ArrayError> {
+        self.inner.get_checked(idx)?;
+        Ok(replace(&mut self.inner.as_mut_slice()[idx], val))
+    }
+
+    /// Borrow the elements in `range`, or `None` if it's out of range
+    pub fn slice(&self, range: Range<usize>) -> Option<&[T]> {
+        self.inner.as_slice().get(range)
+    }
+
+    /// Clone `self`
+    ///
+    /// Error states:
+    ///  * see [`Array::try_clone`]
+    pub fn try_clone(&self) -> Result<Self, ArrayError>
+    where T: Clone
+    {
+        Ok(CheckedArray { inner: self.inner.try_clone()? })
+    }
+
+    pub fn len(&self) -> usize { self.inner.cap() }
+
+    pub fn is_empty(&self) -> bool { self.inner.is_empty() }
+
+    pub fn as_slice(&self) -> &[T] { self.inner.as_slice() }
+
+    /// Unwrap the underlying `Array`
+    pub fn into_array(self) -> Array<T> {
+        self.inner
+    }
+}
@@ -0,0 +1,187 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+        pod::Pod,
+    },
+    std::{
+        fs::{ File, OpenOptions },
+        io::{ Read, Seek, SeekFrom, Write },
+        mem::size_of,
+        path::Path,
+    },
+};
+
+struct Chunk<T> {
+    idx: usize,
+    data: Array<T>,
+    dirty: bool,
+}
+
+/// An array of `Pod` values stored in a file, with at most `max_resident`
+/// chunks of `chunk_len` elements held in memory at once
+///
+/// The least recently used chunk is written back (if changed) and dropped
+/// to make room for a new one. Changes reach the file on eviction,
+/// [`ChunkedArray::flush`], or drop
+pub struct ChunkedArray<T: Pod> {
+    file: File,
+    len: usize,
+    chunk_len: usize,
+    max_resident: usize,
+    // Least recently used first
+    resident: Vec<Chunk<T>>,
+} impl<T: Pod> ChunkedArray<T> {
+    /// Create a new ChunkedArray of `len` zeros backed by the file at `path`,
+    /// replacing anything already there
+    ///
+    /// Error states:
+    ///  * `chunk_len` or `max_resident` is 0
+    ///  * the file can't be created or resized
+    pub fn create<P: AsRef<Path>>(path: P, len: usize, chunk_len: usize, max_resident: usize) -> Result<Self, ArrayError> {
+        let file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path).map_err(ArrayError::new)?;
+        let bytes = len.checked_mul(size_of::<T>()).ok_or_else(|| ArrayError::new("Overflow when getting file size"))?;
+        file.set_len(bytes as u64).map_err(ArrayError::new)?;
+        Self::from_file(file, len, chunk_len, max_resident)
+    }
+
+    /// Open the existing array in the file at `path`
+    ///
+    /// Error states:
+    ///  * `chunk_len` or `max_resident` is 0
+    ///  * the file can't be opened
+    ///  * the file's size isn't a multiple of the size of `T`
+    pub fn open<P: AsRef<Path>>(path: P, chunk_len: usize, max_resident: usize) -> Result<Self, ArrayError> {
+        let file = OpenOptions::new().read(true).write(true).open(path).map_err(ArrayError::new)?;
+        let bytes = file.metadata().map_err(ArrayError::new)?.len() as usize;
+        if size_of::<T>() == 0 || !bytes.is_multiple_of(size_of::<T>()) {
+            return Err(ArrayError::new(format!("file of {} bytes doesn't hold a whole number of elements", bytes)));
+        }
+        Self::from_file(file, bytes / size_of::<T>(), chunk_len, max_resident)
+    }
+
+    fn from_file(file: File, len: usize, chunk_len: usize, max_resident: usize) -> Result<Self, ArrayError> {
+        if chunk_len == 0 || max_resident == 0 {
+            return Err(ArrayError::new("chunk length and resident chunk count must be greater than 0"));
+        }
+        Ok(ChunkedArray { file, len, chunk_len, max_resident, resident: Vec::with_capacity(max_resident) })
+    }
+
+    /// The number of elements
+    pub fn len(&self) -> usize { self.len }
+
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// The number of chunks currently in memory
+    pub fn resident_chunks(&self) -> usize { self.resident.len() }
+
+    /// Get the value at `idx`, paging its chunk in if needed
+    ///
+    /// Error states:
+    ///  * `idx` is not less than the length of the array
+    ///  * reading the chunk, or writing back the one it replaces, fails
+    pub fn get(&mut self, idx: usize) -> Result<T, ArrayError> {
+        let chunk_len = self.chunk_len;
+        let chunk = self.chunk_for(idx)?;
+        Ok(chunk.data[idx % chunk_len])
+    }
+
+    /// Set the value at `idx` to `val`, paging its chunk in if needed
+    ///
+    /// Error states:
+    ///  * see [`ChunkedArray::get`]
+    pub fn set(&mut self, idx: usize, val: T) -> Result<(), ArrayError> {
+        let chunk_len = self.chunk_len;
+        let chunk = self.chunk_for(idx)?;
+        chunk.data[idx % chunk_len] = val;
+        chunk.dirty = true;
+        Ok(())
+    }
+
+    /// Iterate over the values in order, one chunk in memory at a time
+    pub fn iter(&mut self) -> ChunkedIter<'_, T> {
+        ChunkedIter { arr: self, idx: 0 }
+    }
+
+    /// Write every changed chunk back to the file
+    ///
+    /// Error states:
+    ///  * writing to the file fails
+    pub fn flush(&mut self) -> Result<(), ArrayError> {
+        for chunk in self.resident.iter_mut().filter(|chunk| chunk.dirty) {
+            Self::write_chunk(&mut self.file, self.chunk_len, chunk)?;
+        }
+        self.file.flush().map_err(ArrayError::new)
+    }
+
+    /// The resident chunk holding `idx`, now the most recently used
+    fn chunk_for(&mut self, idx: usize) -> Result<&mut Chunk<T>, ArrayError> {
+        if idx >= self.len {
+            return Err(ArrayError::new(format!("index {} out of range for length {}", idx, self.len)));
+        }
+        let chunk_idx = idx / self.chunk_len;
+        match self.resident.iter().position(|chunk| chunk.idx == chunk_idx) {
+            Some(pos) => {
+                let chunk = self.resident.remove(pos);
+                self.resident.push(chunk);
+            }
+            None => {
+                if self.resident.len() == self.max_resident {
+                    // Only let go of the chunk once its changes are on disk
+                    Self::write_chunk(&mut self.file, self.chunk_len, &mut self.resident[0])?;
+                    self.resident.remove(0);
+                }
+                let chunk = self.read_chunk(chunk_idx)?;
+                self.resident.push(chunk);
+            }
+        }
+        Ok(self.resident.last_mut().expect("a chunk was just pushed"))
+    }
+
+    fn read_chunk(&mut self, idx: usize) -> Result<Chunk<T>, ArrayError> {
+        let start = idx * self.chunk_len;
+        // The last chunk may be short
+        let mut data = Array::new(self.chunk_len.min(self.len - start))?;
+        self.file.seek(SeekFrom::Start((start * size_of::<T>()) as u64)).map_err(ArrayError::new)?;
+        self.file.read_exact(data.as_bytes_mut()).map_err(ArrayError::new)?;
+        Ok(Chunk { idx, data, dirty: false })
+    }
+
+    fn write_chunk(file: &mut File, chunk_len: usize, chunk: &mut Chunk<T>) -> Result<(), ArrayError> {
+        if !chunk.dirty {
+            return Ok(());
+        }
+        file.seek(SeekFrom::Start((chunk.idx * chunk_len * size_of::<T>()) as u64)).map_err(ArrayError::new)?;
+        file.write_all(chunk.data.as_bytes()).map_err(ArrayError::new)?;
+        chunk.dirty = false;
+        Ok(())
+    }
+}
+
+impl<T: Pod> Drop for ChunkedArray<T> {
+    fn drop(&mut self) {
+        // Errors can't be reported from here, call `flush` to see them
+        let _ = self.flush();
+    }
+}
+
+/// Sequential iterator over a [`ChunkedArray`], see [`ChunkedArray::iter`]
+pub struct ChunkedIter<'a, T: Pod> {
+    arr: &'a mut ChunkedArray<T>,
+    idx: usize,
+} impl<T: Pod> Iterator for ChunkedIter<'_, T> {
+    type Item = Result<T, ArrayError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx == self.arr.len {
+            return None;
+        }
+        self.idx += 1;
+        Some(self.arr.get(self.idx - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.arr.len - self.idx;
+        (remaining, Some(remaining))
+    }
+}
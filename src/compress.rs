@@ -0,0 +1,76 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    flate2::{ Compress, Compression, Decompress, FlushCompress, FlushDecompress, Status },
+};
+
+/// The first `len` bytes of `arr`, in an `Array` of their own
+fn prefix(arr: &Array<u8>, len: usize) -> Result<Array<u8>, ArrayError> {
+    Array::from_iter_exact(len, arr.as_slice()[..len].iter().copied())
+}
+
+// zlib compression for byte arrays
+impl Array<u8> {
+
+    /// Compress `self` into a zlib stream at `level`, 0 (none) to 9 (best)
+    ///
+    /// Error states:
+    ///  * `level` is greater than 9
+    ///  * see [`Array::new`]
+    pub fn compress(&self, level: u32) -> Result<Array<u8>, ArrayError> {
+        if level > 9 {
+            return Err(ArrayError::new(format!("compression level {} is out of range 0-9", level)));
+        }
+        let n = self.cap();
+        // zlib's compressBound, a first guess at the most deflate produces for `n` bytes
+        let bound = n + (n >> 12) + (n >> 14) + (n >> 25) + 13;
+        let mut out = Array::<u8>::new(bound)?;
+        let mut z = Compress::new(Compression::new(level), true);
+        loop {
+            let (read, written) = (z.total_in() as usize, z.total_out() as usize);
+            let status = z
+                .compress(&self.as_slice()[read..], &mut out.as_mut_slice()[written..], FlushCompress::Finish)
+                .map_err(ArrayError::new)?;
+            let written = z.total_out() as usize;
+            match status {
+                Status::StreamEnd => return prefix(&out, written),
+                // The bound was short, zlib needs more room to finish the stream
+                Status::Ok | Status::BufError => {
+                    let mut bigger = Array::<u8>::new(out.cap().saturating_mul(2))?;
+                    bigger.as_mut_slice()[..written].copy_from_slice(&out.as_slice()[..written]);
+                    out = bigger;
+                }
+            }
+        }
+    }
+
+    /// Decompress a zlib stream produced by [`Array::compress`]
+    ///
+    /// Error states:
+    ///  * `self` is not a valid, complete zlib stream
+    ///  * see [`Array::new`]
+    pub fn decompress(&self) -> Result<Array<u8>, ArrayError> {
+        let mut out = Array::<u8>::new(self.cap().saturating_mul(4).max(64))?;
+        let mut z = Decompress::new(true);
+        loop {
+            let (read, written) = (z.total_in() as usize, z.total_out() as usize);
+            let status = z
+                .decompress(&self.as_slice()[read..], &mut out.as_mut_slice()[written..], FlushDecompress::None)
+                .map_err(ArrayError::new)?;
+            let written = z.total_out() as usize;
+            if let Status::StreamEnd = status {
+                return prefix(&out, written);
+            }
+            if written < out.cap() {
+                // Room left over but no end of stream, so the input ran out
+                return Err(ArrayError::new("truncated zlib stream"));
+            }
+            // Out of room, move what's done into a buffer twice the size
+            let mut bigger = Array::<u8>::new(out.cap().saturating_mul(2))?;
+            bigger.as_mut_slice()[..written].copy_from_slice(out.as_slice());
+            out = bigger;
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+        padded::CachePadded,
+    },
+    std::{
+        sync::{ PoisonError, RwLock },
+    },
+};
+
+/// An `Array` split into contiguous shards, each behind its own `RwLock`, so
+/// threads working on different regions don't contend
+///
+/// Each lock sits on its own cache line, so taking one doesn't slow down
+/// threads using its neighbours
+pub struct ConcurrentArray<T> {
+    shards: Array<CachePadded<RwLock<Array<T>>>>,
+    shard_len: usize,
+    len: usize,
+} impl<T> ConcurrentArray<T> {
+    /// Create a new ConcurrentArray of `len` copies of `val`, split into at
+    /// most `shards` locks
+    ///
+    /// Error states:
+    ///  * `shards` is 0
+    ///  * see [`Array::new`]
+    pub fn new(len: usize, shards: usize, val: T) -> Result<Self, ArrayError>
+    where T: Clone
+    {
+        if shards == 0 {
+            return Err(ArrayError::new("ConcurrentArray needs at least 1 shard"));
+        }
+        let shard_len = len.div_ceil(shards).max(1);
+        let count = len.div_ceil(shard_len);
+        let shards = (0..count).map(|i| {
+            let this_len = shard_len.min(len - i * shard_len);
+            Array::from_iter_exact(this_len, std::iter::repeat_n(val.clone(), this_len)).map(|shard| CachePadded::new(RwLock::new(shard)))
+        });
+        let shards = shards.collect::<Result<Vec<_>, ArrayError>>()?;
+        Ok(ConcurrentArray {
+            shards: Array::from_iter_exact(count, shards)?,
+            shard_len,
+            len,
+        })
+    }
+
+    /// The shard holding `idx`, and `idx`'s offset in it
+    fn locate(&self, idx: usize) -> Result<(&RwLock<Array<T>>, usize), ArrayError> {
+        if idx >= self.len {
+            return Err(ArrayError::new("index out of range"));
+        }
+        Ok((&self.shards[idx / self.shard_len], idx % self.shard_len))
+    }
+
+    /// Get a copy of the value at `idx`
+    ///
+    /// Error states:
+    ///  * `idx` is not less than the length of the array
+    ///  * the shard's lock was poisoned by a panic
+    pub fn get(&self, idx: usize) -> Result<T, ArrayError>
+    where T: Clone
+    {
+        let (shard, offs) = self.locate(idx)?;
+        let shard = shard.read().map_err(|_| ArrayError::new("shard lock poisoned"))?;
+        Ok(shard.as_slice()[offs].clone())
+    }
+
+    /// Set the value at `idx` to `val`
+    ///
+    /// Error states:
+    ///  * see [`ConcurrentArray::update`]
+    pub fn set(&self, idx: usize, val: T) -> Result<(), ArrayError> {
+        self.update(idx, |slot| *slot = val)
+    }
+
+    /// Run `f` on the value at `idx` while holding its shard's write lock
+    ///
+    /// Error states:
+    ///  * `idx` is not less than the length of the array
+    ///  * the shard's lock was poisoned by a panic
+    pub fn update<F, R>(&self, idx: usize, f: F) -> Result<R, ArrayError>
+    where F: FnOnce(&mut T) -> R
+    {
+        let (shard, offs) = self.locate(idx)?;
+        let mut shard = shard.write().map_err(|_| ArrayError::new("shard lock poisoned"))?;
+        Ok(f(&mut shard.as_mut_slice()[offs]))
+    }
+
+    pub fn len(&self) -> usize { self.len }
+
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// The number of locks the array is split into
+    pub fn shard_count(&self) -> usize { self.shards.cap() }
+}
+
+impl<T> Drop for ConcurrentArray<T> {
+    fn drop(&mut self) {
+        for shard in self.shards.iter_mut() {
+            let elems = shard.get_mut().unwrap_or_else(PoisonError::into_inner);
+            unsafe { elems.drop_elements() }
+        }
+        unsafe { self.shards.drop_elements() }
+    }
+}
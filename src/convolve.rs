@@ -0,0 +1,69 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::ops::{ Add, Mul },
+};
+
+/// Which part of the full convolution of a signal of `n` elements with a
+/// kernel of `m` elements to keep
+///
+/// A kernel longer than the signal is allowed in every mode, the shorter of
+/// the two slides along the longer. For [`Array::convolve`] this gives the
+/// same results as NumPy's `convolve`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConvolveMode {
+    /// Every point where the signal and kernel overlap, `n + m - 1` elements
+    Full,
+    /// The `max(n, m)` elements of the full output starting at
+    /// `(min(n, m) - 1) / 2`, so `n` elements centered on it when `m <= n`
+    Same,
+    /// Only the points where the shorter of the two fits entirely in the
+    /// longer, `max(n, m) - min(n, m) + 1` elements
+    Valid,
+}
+
+impl<T> Array<T>
+where T: Copy + Default + Add<Output=T> + Mul<Output=T>
+{
+    /// Convolve `self` with `kernel`
+    ///
+    /// Error states:
+    ///  * `self` or `kernel` is empty
+    ///  * see [`Array::new`]
+    pub fn convolve(&self, kernel: &Array<T>, mode: ConvolveMode) -> Result<Array<T>, ArrayError> {
+        self.convolve_slice(kernel.as_slice(), mode)
+    }
+
+    /// Cross-correlate `self` with `kernel`, the same as convolving with
+    /// `kernel` reversed
+    ///
+    /// Error states:
+    ///  * see [`Array::convolve`]
+    pub fn correlate(&self, kernel: &Array<T>, mode: ConvolveMode) -> Result<Array<T>, ArrayError> {
+        let reversed: Vec<T> = kernel.iter().rev().copied().collect();
+        self.convolve_slice(&reversed, mode)
+    }
+
+    fn convolve_slice(&self, kernel: &[T], mode: ConvolveMode) -> Result<Array<T>, ArrayError> {
+        let (n, m) = (self.cap(), kernel.len());
+        if n == 0 || m == 0 {
+            return Err(ArrayError::new("cannot convolve an empty array"));
+        }
+        let (short, long) = (n.min(m), n.max(m));
+        let (start, len) = match mode {
+            ConvolveMode::Full => (0, n + m - 1),
+            ConvolveMode::Same => ((short - 1) / 2, long),
+            ConvolveMode::Valid => (short - 1, long - short + 1),
+        };
+        let signal = self.as_slice();
+        // out[k] = sum of signal[i] * kernel[k - i] over the overlap
+        let out = (start..start + len).map(|k| {
+            let lo = k.saturating_sub(m - 1);
+            let hi = k.min(n - 1);
+            (lo..=hi).fold(T::default(), |acc, i| acc + signal[i] * kernel[k - i])
+        });
+        Array::from_iter_exact(len, out)
+    }
+}
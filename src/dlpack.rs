@@ -0,0 +1,231 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+        pod::Pod,
+    },
+    std::{
+        convert::TryFrom,
+        ffi::c_void,
+        mem::size_of,
+        ops::Deref,
+        ptr::null_mut,
+        slice::from_raw_parts,
+    },
+};
+
+/// `DLDeviceType::kDLCPU`
+pub const DL_CPU: i32 = 1;
+
+/// `DLDataTypeCode::kDLInt`
+pub const DL_INT: u8 = 0;
+/// `DLDataTypeCode::kDLUInt`
+pub const DL_UINT: u8 = 1;
+/// `DLDataTypeCode::kDLFloat`
+pub const DL_FLOAT: u8 = 2;
+
+/// DLPack's `DLDevice`
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DLDevice {
+    pub device_type: i32,
+    pub device_id: i32,
+}
+
+/// DLPack's `DLDataType`
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DLDataType {
+    pub code: u8,
+    pub bits: u8,
+    pub lanes: u16,
+}
+
+/// DLPack's `DLTensor`
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLTensor {
+    pub data: *mut c_void,
+    pub device: DLDevice,
+    pub ndim: i32,
+    pub dtype: DLDataType,
+    pub shape: *mut i64,
+    /// Null for a compact, row-major tensor
+    pub strides: *mut i64,
+    pub byte_offset: u64,
+}
+
+/// DLPack's `DLManagedTensor`, the handle exchanged with other frameworks
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLManagedTensor {
+    pub dl_tensor: DLTensor,
+    pub manager_ctx: *mut c_void,
+    /// Called by the consumer once it's done with the tensor
+    pub deleter: Option<unsafe extern "C" fn(*mut DLManagedTensor)>,
+}
+
+/// Element types with a DLPack data type
+///
+/// # Safety
+/// `DTYPE` must describe `Self` exactly, tensors of that type are read as `Self`
+pub unsafe trait DlpackElement: Pod {
+    const DTYPE: DLDataType;
+}
+
+macro_rules! impl_dlpack_element {
+    ($($t:ty => $code:expr;)*) => {$(
+        unsafe impl DlpackElement for $t {
+            const DTYPE: DLDataType = DLDataType { code: $code, bits: (size_of::<$t>() * 8) as u8, lanes: 1 };
+        }
+    )*};
+}
+
+impl_dlpack_element! {
+    i8 => DL_INT; i16 => DL_INT; i32 => DL_INT; i64 => DL_INT;
+    u8 => DL_UINT; u16 => DL_UINT; u32 => DL_UINT; u64 => DL_UINT;
+    f32 => DL_FLOAT; f64 => DL_FLOAT;
+}
+
+// Owns the exported `Array`, `managed` comes first so the two share an address
+#[repr(C)]
+struct ExportCtx<T> {
+    managed: DLManagedTensor,
+    shape: i64,
+    arr: Array<T>,
+}
+
+unsafe extern "C" fn delete_export<T>(managed: *mut DLManagedTensor) {
+    drop(Box::from_raw((*managed).manager_ctx as *mut ExportCtx<T>));
+}
+
+impl<T: DlpackElement> Array<T> {
+
+    /// Hand `self` to a DLPack consumer as a 1D CPU tensor, without copying
+    ///
+    /// The consumer owns the result and frees it by calling its `deleter`
+    pub fn to_dlpack(self) -> *mut DLManagedTensor {
+        let ctx = Box::into_raw(Box::new(ExportCtx {
+            managed: DLManagedTensor {
+                dl_tensor: DLTensor {
+                    data: self.as_ptr() as *mut c_void,
+                    device: DLDevice { device_type: DL_CPU, device_id: 0 },
+                    ndim: 1,
+                    dtype: T::DTYPE,
+                    shape: null_mut(),
+                    strides: null_mut(),
+                    byte_offset: 0,
+                },
+                manager_ctx: null_mut(),
+                deleter: Some(delete_export::<T>),
+            },
+            shape: self.cap() as i64,
+            arr: self,
+        }));
+        unsafe {
+            // `ctx` was just boxed, and stays put until the deleter runs
+            (*ctx).managed.dl_tensor.shape = &mut (*ctx).shape;
+            (*ctx).managed.manager_ctx = ctx as *mut c_void;
+            &mut (*ctx).managed
+        }
+    }
+
+    /// Copy the elements of a DLPack tensor into a new `Array`, then free it
+    ///
+    /// Use [`DlpackTensor`] to read one without copying
+    ///
+    /// # Safety
+    /// See [`DlpackTensor::from_raw`]
+    ///
+    /// Error states:
+    ///  * see [`DlpackTensor::from_raw`]
+    ///  * see [`Array::new`]
+    pub unsafe fn from_dlpack(managed: *mut DLManagedTensor) -> Result<Self, ArrayError> {
+        let tensor = DlpackTensor::<T>::from_raw(managed)?;
+        Array::from_iter_exact(tensor.len(), tensor.iter().copied())
+    }
+}
+
+/// A borrowed view of a 1D CPU DLPack tensor, which calls the producer's
+/// deleter when dropped
+pub struct DlpackTensor<T> {
+    managed: *mut DLManagedTensor,
+    data: *const T,
+    len: usize,
+} impl<T: DlpackElement> DlpackTensor<T> {
+    /// Take ownership of `managed`
+    ///
+    /// On error `managed` is still freed
+    ///
+    /// # Safety
+    /// `managed` must be a valid DLPack tensor not owned by anything else
+    ///
+    /// Error states:
+    ///  * `managed` is null
+    ///  * the tensor isn't on the CPU
+    ///  * the tensor isn't 1D, or isn't contiguous
+    ///  * the tensor's data type isn't `T`
+    ///  * the tensor's length is negative or too large to address
+    ///  * the tensor's data is null, or not aligned for `T`
+    pub unsafe fn from_raw(managed: *mut DLManagedTensor) -> Result<Self, ArrayError> {
+        if managed.is_null() {
+            return Err(ArrayError::new("null DLPack tensor"));
+        }
+        // Freed on any error below
+        let mut view = DlpackTensor { managed, data: null_mut(), len: 0 };
+        let tensor = &(*managed).dl_tensor;
+        if tensor.device.device_type != DL_CPU {
+            return Err(ArrayError::new(format!("DLPack tensor is on device type {}, not the CPU", tensor.device.device_type)));
+        }
+        if tensor.ndim != 1 {
+            return Err(ArrayError::new(format!("DLPack tensor has {} dimensions, expected 1", tensor.ndim)));
+        }
+        if !tensor.strides.is_null() && *tensor.strides != 1 {
+            return Err(ArrayError::new("DLPack tensor isn't contiguous"));
+        }
+        if tensor.dtype != T::DTYPE {
+            return Err(ArrayError::new(format!("DLPack tensor has data type {:?}, expected {:?}", tensor.dtype, T::DTYPE)));
+        }
+        let len = match usize::try_from(*tensor.shape) {
+            Ok(len) if len.checked_mul(size_of::<T>()).is_some_and(|bytes| bytes <= isize::MAX as usize) => len,
+            _ => return Err(ArrayError::new(format!("DLPack tensor has invalid length {}", *tensor.shape))),
+        };
+        if len == 0 {
+            return Ok(view);
+        }
+        if tensor.data.is_null() {
+            return Err(ArrayError::new("DLPack tensor has null data"));
+        }
+        let data = (tensor.data as *const u8).wrapping_add(tensor.byte_offset as usize) as *const T;
+        if !data.is_aligned() {
+            return Err(ArrayError::new(format!("DLPack tensor data at {:p} isn't aligned for its element type", data)));
+        }
+        view.len = len;
+        view.data = data;
+        Ok(view)
+    }
+}
+
+impl<T> Deref for DlpackTensor<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        if self.len == 0 {
+            return &[];
+        }
+        unsafe {
+            // `from_raw` checked this is a contiguous run of `len` `T`s
+            from_raw_parts(self.data, self.len)
+        }
+    }
+}
+
+impl<T> Drop for DlpackTensor<T> {
+    fn drop(&mut self) {
+        unsafe {
+            if let Some(deleter) = (*self.managed).deleter {
+                deleter(self.managed);
+            }
+        }
+    }
+}
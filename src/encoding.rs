@@ -0,0 +1,105 @@
+use crate::{
+    array::Array,
+    error::ArrayError,
+};
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn hex_value(c: u8) -> Result<u8, ArrayError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(ArrayError::new(format!("invalid hex character {:?}", c as char))),
+    }
+}
+
+fn base64_value(c: u8) -> Result<u8, ArrayError> {
+    match c {
+        b'A'..=b'Z' => Ok(c - b'A'),
+        b'a'..=b'z' => Ok(c - b'a' + 26),
+        b'0'..=b'9' => Ok(c - b'0' + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        _ => Err(ArrayError::new(format!("invalid base64 character {:?}", c as char))),
+    }
+}
+
+// Text encodings for byte arrays
+impl Array<u8> {
+
+    /// Encode `self` as lowercase hex
+    pub fn to_hex(&self) -> String {
+        let mut out = String::with_capacity(self.cap() * 2);
+        for &b in self.as_slice() {
+            out.push(HEX[(b >> 4) as usize] as char);
+            out.push(HEX[(b & 0xf) as usize] as char);
+        }
+        out
+    }
+
+    /// Decode a hex string, in either case
+    ///
+    /// Error states:
+    ///  * `s` has an odd length
+    ///  * `s` contains a non-hex character
+    pub fn from_hex(s: &str) -> Result<Self, ArrayError> {
+        let s = s.as_bytes();
+        if !s.len().is_multiple_of(2) {
+            return Err(ArrayError::new("hex string has an odd length"));
+        }
+        let mut arr = Self::new(s.len() / 2)?;
+        for (slot, pair) in arr.as_mut_slice().iter_mut().zip(s.chunks_exact(2)) {
+            *slot = (hex_value(pair[0])? << 4) | hex_value(pair[1])?;
+        }
+        Ok(arr)
+    }
+
+    /// Encode `self` as padded standard base64
+    pub fn to_base64(&self) -> String {
+        let mut out = String::with_capacity(self.cap().div_ceil(3) * 4);
+        for chunk in self.as_slice().chunks(3) {
+            let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+            let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    out.push(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+                } else {
+                    out.push('=');
+                }
+            }
+        }
+        out
+    }
+
+    /// Decode padded standard base64
+    ///
+    /// Error states:
+    ///  * the length of `s` is not a multiple of 4
+    ///  * `s` contains a character outside the base64 alphabet
+    ///  * padding appears anywhere but the end
+    pub fn from_base64(s: &str) -> Result<Self, ArrayError> {
+        let s = s.as_bytes();
+        if !s.len().is_multiple_of(4) {
+            return Err(ArrayError::new("base64 string length is not a multiple of 4"));
+        }
+        let pad = s.iter().rev().take(2).take_while(|&&c| c == b'=').count();
+        let mut arr = Self::new(s.len() / 4 * 3 - pad)?;
+        let out = arr.as_mut_slice();
+        for (i, quad) in s.chunks_exact(4).enumerate() {
+            let last = (i + 1) * 4 == s.len();
+            let mut n = 0u32;
+            for (j, &c) in quad.iter().enumerate() {
+                let v = if c == b'=' && last && j >= 4 - pad { 0 } else { base64_value(c)? };
+                n = n << 6 | v as u32;
+            }
+            for (j, &b) in n.to_be_bytes()[1..].iter().enumerate() {
+                if let Some(slot) = out.get_mut(i * 3 + j) {
+                    *slot = b;
+                }
+            }
+        }
+        Ok(arr)
+    }
+}
@@ -0,0 +1,98 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        marker::PhantomData,
+        ops::{ Index, IndexMut },
+    },
+};
+
+/// A fieldless enum usable as the index of an [`EnumArray`]
+///
+/// Implement it with [`enum_index!`](crate::enum_index)
+pub trait EnumIndex: Copy + 'static {
+    /// Every variant, in declaration order
+    const VARIANTS: &'static [Self];
+
+    /// The position of `self` in `VARIANTS`
+    fn index(self) -> usize;
+}
+
+/// Declare a fieldless enum and implement [`EnumIndex`] for it
+///
+/// The enum must derive `Clone` and `Copy`
+///
+/// ```
+/// better_array::enum_index! {
+///     #[derive(Clone, Copy, Debug, PartialEq)]
+///     pub enum Op { Add, Sub, Mul }
+/// }
+/// ```
+#[macro_export]
+macro_rules! enum_index {
+    ($(#[$meta:meta])* $vis:vis enum $name:ident { $($variant:ident),* $(,)? }) => {
+        $(#[$meta])*
+        $vis enum $name { $($variant),* }
+
+        impl $crate::enum_array::EnumIndex for $name {
+            const VARIANTS: &'static [Self] = &[$($name::$variant),*];
+
+            fn index(self) -> usize {
+                self as usize
+            }
+        }
+    };
+}
+
+/// An `Array` with one slot per variant of `E`, indexed by `E`
+pub struct EnumArray<E: EnumIndex, T> {
+    arr: Array<T>,
+    _index: PhantomData<E>,
+} impl<E: EnumIndex, T> EnumArray<E, T> {
+    /// Create a new EnumArray, filling each slot with `f(variant)`
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn from_fn<F>(f: F) -> Result<Self, ArrayError>
+    where F: FnMut(&E) -> T
+    {
+        let arr = Array::from_iter_exact(E::VARIANTS.len(), E::VARIANTS.iter().map(f))?;
+        Ok(EnumArray { arr, _index: PhantomData })
+    }
+
+    /// Create a new EnumArray with every slot set to `val`
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn filled(val: T) -> Result<Self, ArrayError>
+    where T: Clone
+    {
+        Self::from_fn(|_| val.clone())
+    }
+
+    /// Iterate over every variant and its value
+    pub fn iter(&self) -> impl Iterator<Item=(E, &T)> {
+        E::VARIANTS.iter().copied().zip(self.arr.as_slice())
+    }
+
+    /// Borrow the underlying `Array`, in variant order
+    pub fn as_array(&self) -> &Array<T> {
+        &self.arr
+    }
+}
+
+impl<E: EnumIndex, T> Index<E> for EnumArray<E, T> {
+    type Output = T;
+
+    fn index(&self, idx: E) -> &T {
+        &self.arr.as_slice()[idx.index()]
+    }
+}
+
+impl<E: EnumIndex, T> IndexMut<E> for EnumArray<E, T> {
+    fn index_mut(&mut self, idx: E) -> &mut T {
+        &mut self.arr.as_mut_slice()[idx.index()]
+    }
+}
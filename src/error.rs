@@ -1,15 +1,33 @@
-
 use std::{
     fmt,
     error::Error,
 };
 
+/// What went wrong, for callers that need to tell errors apart
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// Anything without a kind of its own, see [`ArrayError::msg`]
+    Other,
+    /// A read of a slot whose value was removed, under the `poison` feature
+    UseAfterRemove,
+}
+
 pub struct ArrayError {
     msg: String,
+    kind: ErrorKind,
 } impl ArrayError {
     pub fn new<S>(msg: S) -> Self where S: ToString {
         Self {
-            msg: msg.to_string()
+            msg: msg.to_string(),
+            kind: ErrorKind::Other,
+        }
+    }
+
+    /// The value at `idx` was read after it was removed
+    pub fn use_after_remove(idx: usize) -> Self {
+        Self {
+            msg: format!("use after remove: the value at index {} was removed", idx),
+            kind: ErrorKind::UseAfterRemove,
         }
     }
 
@@ -17,6 +35,10 @@ pub struct ArrayError {
         &self.msg
     }
 
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
     pub fn matches<S: ToString>(&self, other: S) -> bool{
         self.msg == other.to_string()
     }
@@ -0,0 +1,33 @@
+use std::cell::Cell;
+
+thread_local! {
+    // Kept per thread so tests running in parallel don't eat each other's failures
+    static FAIL_NEXT: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Make the next `n` calls to [`alloc`](crate::array::alloc) on this thread
+/// fail, replacing any failures still pending
+pub fn fail_next(n: usize) {
+    FAIL_NEXT.with(|count| count.set(n));
+}
+
+/// The number of injected failures still pending on this thread
+pub fn pending() -> usize {
+    FAIL_NEXT.with(Cell::get)
+}
+
+/// Cancel any injected failures still pending on this thread
+pub fn clear() {
+    fail_next(0);
+}
+
+/// Whether this allocation should fail, using up one pending failure if so
+pub(crate) fn take_failure() -> bool {
+    FAIL_NEXT.with(|count| match count.get() {
+        0 => false,
+        n => {
+            count.set(n - 1);
+            true
+        }
+    })
+}
@@ -0,0 +1,93 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        iter::repeat_n,
+        ops::{ Add, Range, Sub },
+    },
+};
+
+/// A Fenwick (binary indexed) tree of prefix sums, with O(log n) point
+/// updates and prefix queries
+pub struct FenwickTree<T> {
+    // tree[i - 1] holds the sum of the `i & -i` elements ending at element i - 1
+    tree: Array<T>,
+} impl<T> FenwickTree<T>
+where T: Copy + Default + Add<Output=T> + Sub<Output=T>
+{
+    /// Create a new FenwickTree of `len` zeros
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(len: usize) -> Result<Self, ArrayError> {
+        Ok(FenwickTree { tree: Array::from_iter_exact(len, repeat_n(T::default(), len))? })
+    }
+
+    /// Build a tree over `values` in O(n)
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn from_slice(values: &[T]) -> Result<Self, ArrayError> {
+        let mut tree = Array::from_iter_exact(values.len(), values.iter().copied())?;
+        let nodes = tree.as_mut_slice();
+        for i in 1..=nodes.len() {
+            let parent = i + (i & i.wrapping_neg());
+            if parent <= nodes.len() {
+                nodes[parent - 1] = nodes[parent - 1] + nodes[i - 1];
+            }
+        }
+        Ok(FenwickTree { tree })
+    }
+
+    /// Add `delta` to element `idx`
+    ///
+    /// Error states:
+    ///  * `idx` is not less than the length of the tree
+    pub fn add(&mut self, idx: usize, delta: T) -> Result<(), ArrayError> {
+        let nodes = self.tree.as_mut_slice();
+        if idx >= nodes.len() {
+            return Err(ArrayError::new("index out of range"));
+        }
+        let mut i = idx + 1;
+        while i <= nodes.len() {
+            nodes[i - 1] = nodes[i - 1] + delta;
+            i += i & i.wrapping_neg();
+        }
+        Ok(())
+    }
+
+    /// The sum of the first `end` elements
+    ///
+    /// Error states:
+    ///  * `end` is greater than the length of the tree
+    pub fn prefix_sum(&self, end: usize) -> Result<T, ArrayError> {
+        let nodes = self.tree.as_slice();
+        if end > nodes.len() {
+            return Err(ArrayError::new("index out of range"));
+        }
+        let mut sum = T::default();
+        let mut i = end;
+        while i > 0 {
+            sum = sum + nodes[i - 1];
+            i -= i & i.wrapping_neg();
+        }
+        Ok(sum)
+    }
+
+    /// The sum of the elements in `range`
+    ///
+    /// Error states:
+    ///  * `range` is reversed or extends past the end of the tree
+    pub fn range_sum(&self, range: Range<usize>) -> Result<T, ArrayError> {
+        if range.start > range.end {
+            return Err(ArrayError::new(format!("invalid range {:?}", range)));
+        }
+        Ok(self.prefix_sum(range.end)? - self.prefix_sum(range.start)?)
+    }
+
+    pub fn len(&self) -> usize { self.tree.cap() }
+
+    pub fn is_empty(&self) -> bool { self.tree.cap() == 0 }
+}
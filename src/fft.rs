@@ -0,0 +1,54 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    rustfft::{ FftNum, FftPlanner },
+};
+
+pub use rustfft::num_complex::Complex;
+
+// FFTs, under the `rustfft` feature
+impl<T: FftNum> Array<Complex<T>> {
+
+    /// Replace `self` with its discrete Fourier transform, in place
+    pub fn fft(&mut self) {
+        if self.cap() == 0 {
+            return;
+        }
+        FftPlanner::new().plan_fft_forward(self.cap()).process(self.as_mut_slice());
+    }
+
+    /// Replace `self` with its inverse discrete Fourier transform, in place
+    ///
+    /// The output is scaled by `1 / n`, so `ifft` undoes `fft`
+    pub fn ifft(&mut self) {
+        if self.cap() == 0 {
+            return;
+        }
+        FftPlanner::new().plan_fft_inverse(self.cap()).process(self.as_mut_slice());
+        let scale = T::one() / T::from_usize(self.cap()).expect("length is representable as a float");
+        for val in self.iter_mut() {
+            *val = *val * scale;
+        }
+    }
+
+    /// Create a new `Array` of the real parts of `self`
+    pub fn real_parts(&self) -> Array<T> {
+        self.iter().map(|val| val.re).collect()
+    }
+}
+
+impl<T: FftNum> Array<T> {
+
+    /// Create a new `Array` holding the discrete Fourier transform of the
+    /// real signal `self`, all `n` bins
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn rfft(&self) -> Result<Array<Complex<T>>, ArrayError> {
+        let mut spectrum = Array::from_iter_exact(self.cap(), self.iter().map(|&re| Complex::new(re, T::zero())))?;
+        spectrum.fft();
+        Ok(spectrum)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::array::Array;
+
+macro_rules! impl_sort_floats {
+    ($($t:ty),*) => {$(
+        impl Array<$t> {
+
+            /// Sort the elements in ascending order, with every NaN at the end
+            ///
+            /// Orders by `total_cmp` otherwise, so -0.0 sorts before 0.0
+            pub fn sort_floats(&mut self) {
+                self.as_mut_slice().sort_unstable_by(|a, b| match (a.is_nan(), b.is_nan()) {
+                    (false, false) => a.total_cmp(b),
+                    // `total_cmp` would put NaNs with the sign bit set first
+                    (a_nan, b_nan) => a_nan.cmp(&b_nan),
+                });
+            }
+        }
+    )*};
+}
+
+impl_sort_floats!(f32, f64);
@@ -0,0 +1,81 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+        pod::Pod,
+    },
+    std::{
+        mem::size_of,
+        sync::mpsc::channel,
+    },
+    wgpu::{
+        util::{ BufferInitDescriptor, DeviceExt },
+        Buffer, BufferDescriptor, BufferUsages, CommandEncoderDescriptor,
+        Device, MapMode, PollType, Queue, COPY_BUFFER_ALIGNMENT,
+    },
+};
+
+// wgpu buffer transfers, under the `gpu` feature
+impl<T: Pod> Array<T> {
+
+    /// Create a new GPU buffer holding a copy of `self`
+    ///
+    /// The buffer is padded to a multiple of `COPY_BUFFER_ALIGNMENT` bytes.
+    /// Add `BufferUsages::COPY_SRC` to `usage` to read it back with
+    /// [`Array::read_from_gpu_buffer`]
+    pub fn to_gpu_buffer(&self, device: &Device, usage: BufferUsages) -> Buffer {
+        device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("better-array upload"),
+            contents: self.as_bytes(),
+            usage,
+        })
+    }
+
+    /// Create a new `Array` of the first `len` elements of `buffer`, copied
+    /// through a staging buffer
+    ///
+    /// Blocks until the GPU has finished the copy
+    ///
+    /// Error states:
+    ///  * `buffer` is smaller than `len` elements, rounded up to `COPY_BUFFER_ALIGNMENT`
+    ///  * mapping the staging buffer fails
+    ///  * see [`Array::new`]
+    pub fn read_from_gpu_buffer(device: &Device, queue: &Queue, buffer: &Buffer, len: usize) -> Result<Array<T>, ArrayError> {
+        let mut arr = Array::<T>::new(len)?;
+        let bytes = (size_of::<T>() * len) as u64;
+        if bytes == 0 {
+            return Ok(arr);
+        }
+        // Copies must be a multiple of `COPY_BUFFER_ALIGNMENT`
+        let padded = bytes.div_ceil(COPY_BUFFER_ALIGNMENT) * COPY_BUFFER_ALIGNMENT;
+        if buffer.size() < padded {
+            return Err(ArrayError::new(format!("buffer holds {} bytes, need {}", buffer.size(), padded)));
+        }
+        let staging = device.create_buffer(&BufferDescriptor {
+            label: Some("better-array download"),
+            size: padded,
+            usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+        encoder.copy_buffer_to_buffer(buffer, 0, &staging, 0, padded);
+        queue.submit([encoder.finish()]);
+
+        let slice = staging.slice(..);
+        let (done, mapped) = channel();
+        slice.map_async(MapMode::Read, move |res| {
+            let _ = done.send(res);
+        });
+        if let Err(err) = device.poll(PollType::Wait) {
+            return Err(ArrayError::new(format!("failed to wait for the GPU: {}", err)));
+        }
+        match mapped.recv() {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => return Err(ArrayError::new(format!("failed to map the staging buffer: {}", err))),
+            Err(_) => return Err(ArrayError::new("staging buffer was never mapped")),
+        }
+        arr.as_bytes_mut().copy_from_slice(&slice.get_mapped_range()[..bytes as usize]);
+        staging.unmap();
+        Ok(arr)
+    }
+}
@@ -0,0 +1,55 @@
+use {
+    crate::error::ArrayError,
+    std::slice::from_raw_parts,
+};
+
+/// The byte written to the guard regions around every allocation
+pub const CANARY: u8 = 0xAB;
+
+/// Size of each guard region, at least 16 bytes and a multiple of `align`
+const fn guard_size(align: usize) -> usize {
+    if align > 16 { align } else { 16 }
+}
+
+/// Size of an allocation of `size` bytes once guard regions are added
+pub(crate) const fn padded_size(size: usize, align: usize) -> Option<usize> {
+    size.checked_add(guard_size(align) * 2)
+}
+
+/// Fill the guard regions of the padded allocation at `base`, returning the
+/// start of the `size` usable bytes between them
+pub(crate) unsafe fn arm(base: *mut u8, size: usize, align: usize) -> *mut u8 {
+    if base.is_null() {
+        return base;
+    }
+    let guard = guard_size(align);
+    base.write_bytes(CANARY, guard);
+    base.add(guard + size).write_bytes(CANARY, guard);
+    base.add(guard)
+}
+
+/// Check the guard regions around the `size` usable bytes at `ptr`
+///
+/// Error states:
+///  * a canary byte before or after the buffer has been overwritten
+pub(crate) unsafe fn check(ptr: *const u8, size: usize, align: usize) -> Result<(), ArrayError> {
+    let guard = guard_size(align);
+    let before = from_raw_parts(ptr.sub(guard), guard);
+    let after = from_raw_parts(ptr.add(size), guard);
+    if let Some(offs) = before.iter().rposition(|&b| b != CANARY) {
+        return Err(ArrayError::new(format!("buffer underrun detected {} bytes before the array", guard - offs)));
+    }
+    if let Some(offs) = after.iter().position(|&b| b != CANARY) {
+        return Err(ArrayError::new(format!("buffer overrun detected {} bytes past the array", offs)));
+    }
+    Ok(())
+}
+
+/// Check the guard regions around `ptr`, panicking if either was overwritten,
+/// and return the start of the padded allocation
+pub(crate) unsafe fn disarm(ptr: *mut u8, size: usize, align: usize) -> *mut u8 {
+    if let Err(e) = check(ptr, size, align) {
+        panic!("{}", e.msg());
+    }
+    ptr.sub(guard_size(align))
+}
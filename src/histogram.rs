@@ -0,0 +1,86 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::convert::TryFrom,
+};
+
+macro_rules! impl_bincount {
+    ($($t:ty),*) => {$(
+        impl Array<$t> {
+
+            /// Count the occurrences of each value, element `v` of the result
+            /// is the number of times `v` appears in `self`
+            ///
+            /// The result has `max + 1` elements, or none if `self` is empty
+            ///
+            /// Error states:
+            ///  * the largest value doesn't fit in a `usize`
+            ///  * see [`Array::new`]
+            pub fn bincount(&self) -> Result<Array<usize>, ArrayError> {
+                let len = match self.iter().max() {
+                    Some(&max) => match usize::try_from(max).ok().and_then(|max| max.checked_add(1)) {
+                        Some(len) => len,
+                        None => return Err(ArrayError::new(format!("value {} is too large to count", max))),
+                    },
+                    None => 0,
+                };
+                let mut counts = Array::<usize>::new(len)?;
+                // `new` zeroes the counts
+                for &val in self.iter() {
+                    counts.as_mut_slice()[val as usize] += 1;
+                }
+                Ok(counts)
+            }
+        }
+    )*};
+}
+
+impl_bincount!(u8, u16, u32, u64, usize);
+
+macro_rules! impl_histogram {
+    ($($t:ty),*) => {$(
+        impl Array<$t> {
+
+            /// Count the elements falling in each of `bins` equal width bins
+            /// spanning `range`
+            ///
+            /// Bins are half open except the last, which includes `range.1`.
+            /// Elements outside `range` and NaNs aren't counted
+            ///
+            /// Error states:
+            ///  * `bins` is 0
+            ///  * `range` isn't finite or is empty
+            ///  * the width of `range` overflows to infinity
+            ///  * see [`Array::new`]
+            pub fn histogram(&self, bins: usize, range: ($t, $t)) -> Result<Array<usize>, ArrayError> {
+                let (lo, hi) = range;
+                if bins == 0 {
+                    return Err(ArrayError::new("histogram needs at least one bin"));
+                }
+                if !lo.is_finite() || !hi.is_finite() || lo >= hi {
+                    return Err(ArrayError::new(format!("invalid histogram range {}..{}", lo, hi)));
+                }
+                // `val - lo` is at most this, so it can't overflow either
+                let width = hi - lo;
+                if !width.is_finite() {
+                    return Err(ArrayError::new(format!("histogram range {}..{} is too wide", lo, hi)));
+                }
+                let mut counts = Array::<usize>::new(bins)?;
+                let scale = bins as $t / width;
+                for &val in self.iter() {
+                    // false for NaN as well
+                    if !(lo..=hi).contains(&val) {
+                        continue;
+                    }
+                    let bin = (((val - lo) * scale) as usize).min(bins - 1);
+                    counts.as_mut_slice()[bin] += 1;
+                }
+                Ok(counts)
+            }
+        }
+    )*};
+}
+
+impl_histogram!(f32, f64);
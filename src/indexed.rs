@@ -0,0 +1,118 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        marker::PhantomData,
+        ops::{ Index, IndexMut },
+    },
+};
+
+/// A type that can be used to index an [`IndexedArray`]
+///
+/// Usually a newtype around `usize`, so indices into different arrays can't
+/// be mixed up
+pub trait ArrayIndex: Copy {
+    /// Create an index from its position in the array
+    fn from_index(idx: usize) -> Self;
+
+    /// The position in the array this index refers to
+    fn index(self) -> usize;
+}
+
+impl ArrayIndex for usize {
+    fn from_index(idx: usize) -> Self { idx }
+    fn index(self) -> usize { self }
+}
+
+/// An `Array` that can only be indexed by `I`
+pub struct IndexedArray<I: ArrayIndex, T> {
+    arr: Array<T>,
+    _index: PhantomData<fn(I)>,
+} impl<I: ArrayIndex, T> IndexedArray<I, T> {
+    /// Create a new IndexedArray of size `size`
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(size: usize) -> Result<Self, ArrayError> {
+        Ok(Self::from_array(Array::new(size)?))
+    }
+
+    /// Wrap an existing `Array`
+    pub fn from_array(arr: Array<T>) -> Self {
+        IndexedArray { arr, _index: PhantomData }
+    }
+
+    /// Get a reference to the value at `idx`, or `None` if it's out of range
+    pub fn get(&self, idx: I) -> Option<&T> {
+        self.arr.get(idx.index())
+    }
+
+    /// Get a mutable reference to the value at `idx`, or `None` if it's out of range
+    pub fn get_mut(&mut self, idx: I) -> Option<&mut T> {
+        self.arr.get_mut(idx.index())
+    }
+
+    /// Get a reference to the value at `idx`
+    ///
+    /// Error states:
+    ///  * see [`Array::get_checked`]
+    pub fn get_checked(&self, idx: I) -> Result<&T, ArrayError> {
+        self.arr.get_checked(idx.index())
+    }
+
+    /// Set the value at `idx` to `val`
+    ///
+    /// Error states:
+    ///  * see [`Array::set`]
+    pub fn set(&mut self, idx: I, val: T) -> Result<(), ArrayError> {
+        self.arr.set(idx.index(), val)
+    }
+
+    /// Delete and return the value at `idx`
+    ///
+    /// Error states:
+    ///  * see [`Array::pop`]
+    pub fn pop(&mut self, idx: I) -> Result<T, ArrayError> {
+        self.arr.pop(idx.index())
+    }
+
+    /// Iterate over every valid index
+    pub fn indices(&self) -> impl Iterator<Item=I> {
+        (0..self.arr.cap()).map(I::from_index)
+    }
+
+    pub fn cap(&self) -> usize { self.arr.cap() }
+
+    /// Borrow the underlying `Array`
+    pub fn as_array(&self) -> &Array<T> {
+        &self.arr
+    }
+
+    /// Unwrap the underlying `Array`
+    pub fn into_array(self) -> Array<T> {
+        self.arr
+    }
+}
+
+// Panics when out of range, like indexing an `Array`
+impl<I: ArrayIndex, T> Index<I> for IndexedArray<I, T> {
+    type Output = T;
+
+    fn index(&self, idx: I) -> &T {
+        &self.arr[idx.index()]
+    }
+}
+
+impl<I: ArrayIndex, T> IndexMut<I> for IndexedArray<I, T> {
+    fn index_mut(&mut self, idx: I) -> &mut T {
+        &mut self.arr[idx.index()]
+    }
+}
+
+impl<I: ArrayIndex, T> From<Array<T>> for IndexedArray<I, T> {
+    fn from(arr: Array<T>) -> Self {
+        Self::from_array(arr)
+    }
+}
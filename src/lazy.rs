@@ -0,0 +1,54 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        iter::repeat_with,
+        sync::OnceLock,
+    },
+};
+
+/// An `Array` whose slots are each initialized at most once, on first use
+///
+/// Concurrent callers racing on an empty slot run only one initializer, the
+/// others wait for its value
+pub struct LazyArray<T> {
+    cells: Array<OnceLock<T>>,
+} impl<T> LazyArray<T> {
+    /// Create a new LazyArray of `len` empty slots
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(len: usize) -> Result<Self, ArrayError> {
+        Ok(LazyArray { cells: Array::from_iter_exact(len, repeat_with(OnceLock::new).take(len))? })
+    }
+
+    /// Get the value at `idx`, if it's been initialized
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.cells.as_slice().get(idx)?.get()
+    }
+
+    /// Get the value at `idx`, initializing it with `f` if it's empty
+    ///
+    /// Error states:
+    ///  * `idx` is not less than the length of the array
+    pub fn get_or_init<F>(&self, idx: usize, f: F) -> Result<&T, ArrayError>
+    where F: FnOnce() -> T
+    {
+        match self.cells.as_slice().get(idx) {
+            Some(cell) => Ok(cell.get_or_init(f)),
+            None => Err(ArrayError::new("index out of range")),
+        }
+    }
+
+    pub fn len(&self) -> usize { self.cells.cap() }
+
+    pub fn is_empty(&self) -> bool { self.cells.cap() == 0 }
+}
+
+impl<T> Drop for LazyArray<T> {
+    fn drop(&mut self) {
+        unsafe { self.cells.drop_elements() }
+    }
+}
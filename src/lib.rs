@@ -1,9 +1,110 @@
+pub mod arena;
 pub mod array;
+pub mod array_builder;
+pub mod array_cell;
+pub mod array_heap;
+pub mod array_pool;
+pub mod array_string;
+pub mod array_writer;
+#[cfg(feature = "tokio")]
+mod async_io;
+pub mod bloom;
+pub mod checked;
+pub mod chunked;
+#[cfg(feature = "flate2")]
+mod compress;
+pub mod concurrent;
+pub mod convolve;
+pub mod dlpack;
+#[cfg(feature = "encoding")]
+mod encoding;
+pub mod enum_array;
 pub mod error;
+#[cfg(feature = "failpoints")]
+pub mod failpoints;
+pub mod fenwick;
+#[cfg(feature = "rustfft")]
+pub mod fft;
+mod float_sort;
+#[cfg(feature = "gpu")]
+mod gpu;
+#[cfg(feature = "debug-guard")]
+pub mod guard;
+mod histogram;
+pub mod indexed;
+pub mod lazy;
+pub mod lru;
+pub mod mpmc;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+pub mod observed;
+mod overflow;
+pub mod padded;
+#[cfg(feature = "rayon")]
+mod par;
+pub mod persistent;
+pub mod pod;
+#[cfg(feature = "poison")]
+pub mod poison;
+pub mod pool;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "rand")]
+mod random;
+pub mod rle;
+mod rolling;
+#[cfg(feature = "zeroize")]
+pub mod secure;
+pub mod segment_tree;
+pub mod slab;
+pub mod spsc;
+#[cfg(feature = "stats")]
+pub mod stats;
+pub mod strided;
+pub mod transaction;
+pub mod validated;
+pub mod vec_mode;
+pub mod versioned;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub mod prelude {
     pub use crate::{
-        array::{ Array, ArrayIter, alloc },
-        error::{ ArrayError },
+        arena::Arena,
+        array::{ Array, ArrayIter, ArrayLayout, ArrayRawParts, alloc, dealloc },
+        array_builder::ArrayBuilder,
+        array_cell::ArrayCell,
+        array_heap::ArrayHeap,
+        array_pool::ArrayPool,
+        array_string::ArrayString,
+        array_writer::ArrayWriter,
+        bloom::BloomFilter,
+        checked::CheckedArray,
+        chunked::ChunkedArray,
+        concurrent::ConcurrentArray,
+        convolve::ConvolveMode,
+        enum_array::{ EnumArray, EnumIndex },
+        error::{ ArrayError, ErrorKind },
+        fenwick::FenwickTree,
+        indexed::{ ArrayIndex, IndexedArray },
+        lazy::LazyArray,
+        lru::LruCache,
+        mpmc::MpmcQueue,
+        observed::{ Change, ObservedArray },
+        padded::CachePadded,
+        persistent::PersistentArray,
+        pod::Pod,
+        pool::{ Pool, PoolGuard },
+        rle::RleArray,
+        segment_tree::{ SegmentTree, Monoid },
+        slab::{ Slab, SlabKey },
+        spsc::{ SpscQueue, Producer, Consumer },
+        strided::{ StridedView, StridedViewMut },
+        transaction::Transaction,
+        validated::{ ValidatedArray, Validator },
+        vec_mode::VecMode,
+        versioned::VersionedArray,
     };
 }
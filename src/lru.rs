@@ -0,0 +1,190 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        borrow::Borrow,
+        collections::HashMap,
+        hash::Hash,
+        iter::from_fn,
+        mem::replace,
+    },
+};
+
+// Marks the end of the order list
+const NIL: usize = usize::MAX;
+
+struct Node<K, V> {
+    key: K,
+    val: V,
+    // Neighbours towards the most and least recently used ends
+    prev: usize,
+    next: usize,
+}
+
+/// A cache of at most a fixed number of entries, evicting the least recently
+/// used one to make room
+///
+/// Entries live in an `Array`, ordered by a linked list of indices, and the
+/// key index is sized up front, so once full it doesn't allocate
+pub struct LruCache<K, V> {
+    nodes: Array<Option<Node<K, V>>>,
+    index: HashMap<K, usize>,
+    // Slots not holding an entry
+    free: Vec<usize>,
+    // Most and least recently used entries
+    head: usize,
+    tail: usize,
+} impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// Create a new, empty LruCache with room for `cap` entries
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(cap: usize) -> Result<Self, ArrayError> {
+        Ok(LruCache {
+            nodes: Array::from_iter_exact(cap, (0..cap).map(|_| None))?,
+            index: HashMap::with_capacity(cap),
+            free: (0..cap).rev().collect(),
+            head: NIL,
+            tail: NIL,
+        })
+    }
+
+    /// Insert `val` under `key` as the most recently used entry
+    ///
+    /// Returns the entry pushed out, either the old value for `key` or the
+    /// least recently used entry if the cache was full
+    pub fn put(&mut self, key: K, val: V) -> Option<(K, V)> {
+        if let Some(&idx) = self.index.get(&key) {
+            self.touch(idx);
+            let node = self.node_mut(idx);
+            let old = replace(&mut node.val, val);
+            return Some((key, old));
+        }
+        let mut evicted = None;
+        if self.free.is_empty() {
+            if self.tail == NIL {
+                // Zero capacity, nothing can be kept
+                return Some((key, val));
+            }
+            let idx = self.tail;
+            self.unlink(idx);
+            let node = self.nodes[idx].take().unwrap();
+            self.index.remove(&node.key);
+            self.free.push(idx);
+            evicted = Some((node.key, node.val));
+        }
+        let idx = self.free.pop().unwrap();
+        self.index.insert(key.clone(), idx);
+        self.nodes[idx] = Some(Node { key, val, prev: NIL, next: NIL });
+        self.push_front(idx);
+        evicted
+    }
+
+    /// Get the value for `key`, marking it as the most recently used
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        let idx = *self.index.get(key)?;
+        self.touch(idx);
+        Some(&self.node_mut(idx).val)
+    }
+
+    /// Get a mutable reference to the value for `key`, marking it as the most
+    /// recently used
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        let idx = *self.index.get(key)?;
+        self.touch(idx);
+        Some(&mut self.node_mut(idx).val)
+    }
+
+    /// Get the value for `key` without changing the order
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        let idx = *self.index.get(key)?;
+        self.nodes[idx].as_ref().map(|node| &node.val)
+    }
+
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        self.index.contains_key(key)
+    }
+
+    /// Remove the entry for `key`, returning its value
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        let idx = self.index.remove(key)?;
+        self.unlink(idx);
+        self.free.push(idx);
+        self.nodes[idx].take().map(|node| node.val)
+    }
+
+    /// Iterate over the entries, from most to least recently used
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut cur = self.head;
+        from_fn(move || {
+            let node = self.nodes.get(cur)?.as_ref()?;
+            cur = node.next;
+            Some((&node.key, &node.val))
+        })
+    }
+
+    pub fn len(&self) -> usize { self.index.len() }
+
+    pub fn is_empty(&self) -> bool { self.index.is_empty() }
+
+    /// The most entries the cache holds
+    pub fn cap(&self) -> usize { self.nodes.cap() }
+
+    fn node_mut(&mut self, idx: usize) -> &mut Node<K, V> {
+        self.nodes[idx].as_mut().expect("order list points at an empty slot")
+    }
+
+    fn touch(&mut self, idx: usize) {
+        if self.head != idx {
+            self.unlink(idx);
+            self.push_front(idx);
+        }
+    }
+
+    fn unlink(&mut self, idx: usize) {
+        let (prev, next) = {
+            let node = self.node_mut(idx);
+            (node.prev, node.next)
+        };
+        match prev {
+            NIL => self.head = next,
+            p => self.node_mut(p).next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            n => self.node_mut(n).prev = prev,
+        }
+    }
+
+    fn push_front(&mut self, idx: usize) {
+        let head = self.head;
+        {
+            let node = self.node_mut(idx);
+            node.prev = NIL;
+            node.next = head;
+        }
+        match head {
+            NIL => self.tail = idx,
+            h => self.node_mut(h).prev = idx,
+        }
+        self.head = idx;
+    }
+}
+
+impl<K, V> Drop for LruCache<K, V> {
+    fn drop(&mut self) {
+        unsafe { self.nodes.drop_elements() }
+    }
+}
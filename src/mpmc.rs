@@ -0,0 +1,141 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        cell::UnsafeCell,
+        mem::MaybeUninit,
+        sync::atomic::{ AtomicUsize, Ordering },
+        thread::yield_now,
+    },
+};
+
+struct Slot<T> {
+    // `pos` when free for the send at `pos`, `pos + 1` once written,
+    // `pos + cap` once read again
+    seq: AtomicUsize,
+    val: UnsafeCell<MaybeUninit<T>>,
+}
+
+/// A bounded multi-producer multi-consumer queue over a fixed `Array`,
+/// using per-slot sequence numbers (Vyukov's bounded queue)
+pub struct MpmcQueue<T> {
+    buf: Array<Slot<T>>,
+    send_pos: AtomicUsize,
+    recv_pos: AtomicUsize,
+}
+
+// Each slot is only touched by the one sender or receiver that claimed it
+unsafe impl<T: Send> Sync for MpmcQueue<T> {}
+
+impl<T> MpmcQueue<T> {
+    /// Create a new, empty queue with room for `cap` values
+    ///
+    /// Error states:
+    ///  * `cap` is 0
+    ///  * see [`Array::new`]
+    pub fn new(cap: usize) -> Result<Self, ArrayError> {
+        if cap == 0 {
+            return Err(ArrayError::new("MpmcQueue needs a capacity of at least 1"));
+        }
+        let slots = (0..cap).map(|seq| Slot {
+            seq: AtomicUsize::new(seq),
+            val: UnsafeCell::new(MaybeUninit::uninit()),
+        });
+        Ok(MpmcQueue {
+            buf: Array::from_iter_exact(cap, slots)?,
+            send_pos: AtomicUsize::new(0),
+            recv_pos: AtomicUsize::new(0),
+        })
+    }
+
+    fn slot(&self, pos: usize) -> &Slot<T> {
+        &self.buf.as_slice()[pos % self.buf.cap()]
+    }
+
+    /// Send `val`, handing it back if the queue is full
+    pub fn try_send(&self, val: T) -> Result<(), T> {
+        let mut pos = self.send_pos.load(Ordering::Relaxed);
+        loop {
+            let slot = self.slot(pos);
+            let seq = slot.seq.load(Ordering::Acquire);
+            let diff = seq.wrapping_sub(pos) as isize;
+            if diff == 0 {
+                match self.send_pos.compare_exchange_weak(pos, pos.wrapping_add(1), Ordering::Relaxed, Ordering::Relaxed) {
+                    Ok(_) => {
+                        unsafe {
+                            // Claiming `pos` gives this sender the slot
+                            (*slot.val.get()).write(val);
+                        }
+                        slot.seq.store(pos.wrapping_add(1), Ordering::Release);
+                        return Ok(());
+                    }
+                    Err(actual) => pos = actual,
+                }
+            } else if diff < 0 {
+                // The slot still holds the value from a lap ago
+                return Err(val);
+            } else {
+                pos = self.send_pos.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Receive a value, if there is one
+    pub fn try_recv(&self) -> Option<T> {
+        let mut pos = self.recv_pos.load(Ordering::Relaxed);
+        loop {
+            let slot = self.slot(pos);
+            let seq = slot.seq.load(Ordering::Acquire);
+            let diff = seq.wrapping_sub(pos.wrapping_add(1)) as isize;
+            if diff == 0 {
+                match self.recv_pos.compare_exchange_weak(pos, pos.wrapping_add(1), Ordering::Relaxed, Ordering::Relaxed) {
+                    Ok(_) => {
+                        let val = unsafe {
+                            // Claiming `pos` gives this receiver the written slot
+                            (*slot.val.get()).assume_init_read()
+                        };
+                        slot.seq.store(pos.wrapping_add(self.buf.cap()), Ordering::Release);
+                        return Some(val);
+                    }
+                    Err(actual) => pos = actual,
+                }
+            } else if diff < 0 {
+                // Nothing has been written to the slot yet
+                return None;
+            } else {
+                pos = self.recv_pos.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Send `val`, waiting for room if the queue is full
+    pub fn send(&self, mut val: T) {
+        loop {
+            match self.try_send(val) {
+                Ok(()) => return,
+                Err(v) => val = v,
+            }
+            yield_now();
+        }
+    }
+
+    /// Receive a value, waiting for one if the queue is empty
+    pub fn recv(&self) -> T {
+        loop {
+            if let Some(val) = self.try_recv() {
+                return val;
+            }
+            yield_now();
+        }
+    }
+
+    pub fn cap(&self) -> usize { self.buf.cap() }
+}
+
+impl<T> Drop for MpmcQueue<T> {
+    fn drop(&mut self) {
+        while self.try_recv().is_some() {}
+    }
+}
@@ -0,0 +1,40 @@
+use {
+    crate::array::Array,
+    nalgebra::{ DVector, DVectorView, DVectorViewMut, Scalar },
+};
+
+// nalgebra interop, under the `nalgebra` feature
+//
+// `From` impls into `Array` would overlap with `From<Iterator>`, so these
+// are inherent methods
+impl<T: Scalar> Array<T> {
+
+    /// Move `self` into a `nalgebra::DVector`, reusing the allocation
+    ///
+    /// See [`Array::into_boxed_slice`] for when the elements are moved instead
+    pub fn into_dvector(self) -> DVector<T> {
+        DVector::from_vec(self.into_boxed_slice().into_vec())
+    }
+
+    /// Move the elements of `vector` into a new `Array`, reusing the allocation
+    pub fn from_dvector(vector: DVector<T>) -> Self {
+        Self::from_boxed_slice(Vec::from(vector.data).into_boxed_slice())
+    }
+
+    /// Borrow `self` as a `nalgebra::DVectorView`, without copying
+    pub fn as_dvector(&self) -> DVectorView<'_, T> {
+        DVectorView::from_slice(self.as_slice(), self.cap())
+    }
+
+    /// Mutably borrow `self` as a `nalgebra::DVectorViewMut`, without copying
+    pub fn as_dvector_mut(&mut self) -> DVectorViewMut<'_, T> {
+        let len = self.cap();
+        DVectorViewMut::from_slice(self.as_mut_slice(), len)
+    }
+}
+
+impl<T: Scalar> From<Array<T>> for DVector<T> {
+    fn from(arr: Array<T>) -> Self {
+        arr.into_dvector()
+    }
+}
@@ -0,0 +1,50 @@
+use {
+    crate::array::Array,
+    ndarray::{ Array1, ArrayView1, ArrayViewMut1 },
+};
+
+// ndarray interop, under the `ndarray` feature
+//
+// `From` impls into `Array` would overlap with `From<Iterator>`, so these
+// are inherent methods
+impl<T> Array<T> {
+
+    /// Move `self` into an `ndarray::Array1`, reusing the allocation
+    ///
+    /// See [`Array::into_boxed_slice`] for when the elements are moved instead
+    pub fn into_ndarray(self) -> Array1<T> {
+        Array1::from_vec(self.into_boxed_slice().into_vec())
+    }
+
+    /// Move the elements of `arr` into a new `Array`
+    ///
+    /// The allocation is reused when `arr` is contiguous and spans all of
+    /// its buffer, otherwise the elements are moved to a new one
+    pub fn from_ndarray(arr: Array1<T>) -> Self {
+        if !arr.is_standard_layout() {
+            return arr.into_iter().collect();
+        }
+        let len = arr.len();
+        let (mut buf, offset) = arr.into_raw_vec_and_offset();
+        // Drop anything outside the view, e.g. left behind by slicing
+        buf.truncate(offset.unwrap_or(0) + len);
+        buf.drain(..offset.unwrap_or(0));
+        Self::from_boxed_slice(buf.into_boxed_slice())
+    }
+
+    /// Borrow `self` as an `ndarray::ArrayView1`, without copying
+    pub fn as_ndarray(&self) -> ArrayView1<'_, T> {
+        ArrayView1::from(self.as_slice())
+    }
+
+    /// Mutably borrow `self` as an `ndarray::ArrayViewMut1`, without copying
+    pub fn as_ndarray_mut(&mut self) -> ArrayViewMut1<'_, T> {
+        ArrayViewMut1::from(self.as_mut_slice())
+    }
+}
+
+impl<T> From<Array<T>> for Array1<T> {
+    fn from(arr: Array<T>) -> Self {
+        arr.into_ndarray()
+    }
+}
@@ -0,0 +1,75 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::mem::replace,
+};
+
+/// A change made to an [`ObservedArray`]
+#[derive(Debug, PartialEq)]
+pub enum Change<'a, T> {
+    /// The value at `idx` was replaced, by `set` or one slot of `fill`
+    Set { idx: usize, old: &'a T, new: &'a T },
+    /// The value at `idx` was removed by `pop`
+    Pop { idx: usize, old: &'a T },
+}
+
+/// An `Array` that calls `observer` with every change made through it
+pub struct ObservedArray<T, F>
+where F: FnMut(Change<'_, T>)
+{
+    arr: Array<T>,
+    observer: F,
+} impl<T, F> ObservedArray<T, F>
+where F: FnMut(Change<'_, T>)
+{
+    /// Start observing changes to `arr`
+    pub fn new(arr: Array<T>, observer: F) -> Self {
+        ObservedArray { arr, observer }
+    }
+
+    /// Set the value at `idx` to `val`
+    ///
+    /// Error states:
+    ///  * see [`Array::get_checked`]
+    pub fn set(&mut self, idx: usize, val: T) -> Result<(), ArrayError> {
+        self.arr.get_checked(idx)?;
+        let old = replace(&mut self.arr[idx], val);
+        (self.observer)(Change::Set { idx, old: &old, new: &self.arr[idx] });
+        Ok(())
+    }
+
+    /// Fill every slot with `val`, reporting each slot as a `Set`
+    pub fn fill(&mut self, val: T) where T: Clone {
+        for idx in 0..self.arr.cap() {
+            let old = replace(&mut self.arr[idx], val.clone());
+            (self.observer)(Change::Set { idx, old: &old, new: &self.arr[idx] });
+        }
+    }
+
+    /// Delete and return the value at `idx`, see [`Array::pop`]
+    ///
+    /// Error states:
+    ///  * see [`Array::pop`]
+    pub fn pop(&mut self, idx: usize) -> Result<T, ArrayError> {
+        let old = self.arr.pop(idx)?;
+        (self.observer)(Change::Pop { idx, old: &old });
+        Ok(old)
+    }
+
+    /// Get the value at `idx`, or `None` if it's out of range
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.arr.get(idx)
+    }
+
+    /// View the contents as a slice
+    pub fn as_slice(&self) -> &[T] {
+        self.arr.as_slice()
+    }
+
+    /// Stop observing, returning the underlying `Array`
+    pub fn into_array(self) -> Array<T> {
+        self.arr
+    }
+}
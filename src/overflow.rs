@@ -0,0 +1,48 @@
+use crate::{
+    array::Array,
+    error::ArrayError,
+};
+
+// One elementwise and one scalar method per integer op, both in place
+macro_rules! overflow_ops {
+    ($t:ty; $($op:ident: $assign:ident, $scalar:ident;)*) => {$(
+        #[doc = concat!("Replace each element with `", stringify!($op), "` of it and the")]
+        /// matching element of `other`
+        ///
+        /// Error states:
+        ///  * `other` is a different length
+        pub fn $assign(&mut self, other: &Array<$t>) -> Result<(), ArrayError> {
+            if self.cap() != other.cap() {
+                return Err(ArrayError::new(format!("length mismatch: {} != {}", self.cap(), other.cap())));
+            }
+            for (a, b) in self.iter_mut().zip(other.iter()) {
+                *a = a.$op(*b);
+            }
+            Ok(())
+        }
+
+        #[doc = concat!("Replace each element with `", stringify!($op), "` of it and `rhs`")]
+        pub fn $scalar(&mut self, rhs: $t) {
+            for a in self.iter_mut() {
+                *a = a.$op(rhs);
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_overflow {
+    ($($t:ty),*) => {$(
+        impl Array<$t> {
+            overflow_ops! { $t;
+                wrapping_add: wrapping_add_assign, wrapping_add_scalar;
+                wrapping_sub: wrapping_sub_assign, wrapping_sub_scalar;
+                wrapping_mul: wrapping_mul_assign, wrapping_mul_scalar;
+                saturating_add: saturating_add_assign, saturating_add_scalar;
+                saturating_sub: saturating_sub_assign, saturating_sub_scalar;
+                saturating_mul: saturating_mul_assign, saturating_mul_scalar;
+            }
+        }
+    )*};
+}
+
+impl_overflow!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
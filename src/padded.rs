@@ -0,0 +1,41 @@
+use std::ops::{ Deref, DerefMut };
+
+/// Pads and aligns a value to 128 bytes, so values next to each other in an
+/// `Array` never share a cache line
+///
+/// 128 covers the adjacent line prefetcher on x86_64 and the 128 byte lines
+/// on Apple silicon. An `Array<CachePadded<AtomicU64>>` gives each thread a
+/// counter without false sharing
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(align(128))]
+pub struct CachePadded<T> {
+    val: T,
+} impl<T> CachePadded<T> {
+    pub const fn new(val: T) -> Self {
+        CachePadded { val }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.val
+    }
+}
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.val
+    }
+}
+
+impl<T> DerefMut for CachePadded<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.val
+    }
+}
+
+impl<T> From<T> for CachePadded<T> {
+    fn from(val: T) -> Self {
+        CachePadded::new(val)
+    }
+}
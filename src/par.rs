@@ -0,0 +1,52 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::ptr::write,
+    rayon::prelude::*,
+};
+
+// Parallel bulk operations, split across rayon's thread pool
+impl<T: Send> Array<T> {
+
+    /// Fills `self` with clones of `with`, in parallel
+    ///
+    /// The old values are overwritten without being dropped, like [`Array::fill`]
+    pub fn par_fill(&mut self, with: T) where T: Clone {
+        self.as_mut_slice().par_iter_mut().for_each_with(with, |with, slot| unsafe {
+            write(slot, with.clone());
+        });
+    }
+
+    /// Fills `self` with values returned by calling `f` repeatedly, in parallel
+    ///
+    /// The old values are overwritten without being dropped, like [`Array::fill`]
+    pub fn par_fill_with<F>(&mut self, f: F)
+    where F: Fn() -> T + Sync
+    {
+        self.as_mut_slice().par_iter_mut().for_each(|slot| unsafe {
+            write(slot, f());
+        });
+    }
+
+    /// Create a new Array from the result of `f` on each element, in parallel
+    ///
+    /// Each result is written over a zeroed slot of the new Array without
+    /// dropping it, like [`Array::fill`]
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn par_map<U, F>(&self, f: F) -> Result<Array<U>, ArrayError>
+    where T: Sync, U: Send, F: Fn(&T) -> U + Sync
+    {
+        let mut out = Array::<U>::new(self.cap())?;
+        out.as_mut_slice()
+            .par_iter_mut()
+            .zip(self.as_slice().par_iter())
+            .for_each(|(slot, val)| unsafe {
+                write(slot, f(val));
+            });
+        Ok(out)
+    }
+}
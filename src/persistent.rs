@@ -0,0 +1,131 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        sync::Arc,
+    },
+};
+
+const BITS: usize = 5;
+const WIDTH: usize = 1 << BITS;
+const MASK: usize = WIDTH - 1;
+
+enum Node<T> {
+    Branch(Array<Arc<Node<T>>>),
+    Leaf(Array<T>),
+}
+
+impl<T> Drop for Node<T> {
+    fn drop(&mut self) {
+        unsafe {
+            match self {
+                Node::Branch(children) => children.drop_elements(),
+                Node::Leaf(elems) => elems.drop_elements(),
+            }
+        }
+    }
+}
+
+/// An immutable array where [`PersistentArray::set`] returns a new version,
+/// sharing everything but the changed path with the original
+///
+/// Stored as a 32-way trie of `Array` chunks, so a `set` copies
+/// O(log32 n) chunks of up to 32 elements
+pub struct PersistentArray<T> {
+    root: Arc<Node<T>>,
+    // Number of branch levels above the leaves
+    depth: usize,
+    len: usize,
+} impl<T: Clone> PersistentArray<T> {
+    /// Create a new PersistentArray holding a copy of `elems`
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn from_slice(elems: &[T]) -> Result<Self, ArrayError> {
+        let mut depth = 0;
+        while WIDTH << (BITS * depth) < elems.len() {
+            depth += 1;
+        }
+        Ok(PersistentArray { root: Arc::new(Self::build(elems, depth)?), depth, len: elems.len() })
+    }
+
+    fn build(elems: &[T], depth: usize) -> Result<Node<T>, ArrayError> {
+        if depth == 0 {
+            return Ok(Node::Leaf(Array::from_iter_exact(elems.len(), elems.iter().cloned())?));
+        }
+        let children = elems.chunks(WIDTH << (BITS * (depth - 1)))
+            .map(|chunk| Self::build(chunk, depth - 1).map(Arc::new))
+            .collect::<Result<Vec<_>, ArrayError>>()?;
+        Ok(Node::Branch(Array::from_vec(children)))
+    }
+
+    /// Get the value at `idx`, or `None` if it's out of range
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        if idx >= self.len {
+            return None;
+        }
+        let mut node = &*self.root;
+        let mut depth = self.depth;
+        loop {
+            match node {
+                Node::Branch(children) => {
+                    node = &children[(idx >> (BITS * depth)) & MASK];
+                    depth -= 1;
+                }
+                Node::Leaf(elems) => return Some(&elems[idx & MASK]),
+            }
+        }
+    }
+
+    /// Create a new version with the value at `idx` set to `val`, leaving
+    /// `self` unchanged
+    ///
+    /// Error states:
+    ///  * `idx` is not less than the length of the array
+    ///  * see [`Array::new`]
+    pub fn set(&self, idx: usize, val: T) -> Result<Self, ArrayError> {
+        if idx >= self.len {
+            return Err(ArrayError::new(format!("index {} out of range for length {}", idx, self.len)));
+        }
+        Ok(PersistentArray {
+            root: Arc::new(Self::set_in(&self.root, self.depth, idx, val)?),
+            depth: self.depth,
+            len: self.len,
+        })
+    }
+
+    /// Copy `node` with `val` at `idx`, sharing every untouched child
+    fn set_in(node: &Node<T>, depth: usize, idx: usize, val: T) -> Result<Node<T>, ArrayError> {
+        match node {
+            Node::Branch(children) => {
+                let mut copy = Array::from_iter_exact(children.cap(), children.iter().cloned())?;
+                let slot = (idx >> (BITS * depth)) & MASK;
+                copy[slot] = Arc::new(Self::set_in(&children[slot], depth - 1, idx, val)?);
+                Ok(Node::Branch(copy))
+            }
+            Node::Leaf(elems) => {
+                let mut copy = Array::from_iter_exact(elems.cap(), elems.iter().cloned())?;
+                copy[idx & MASK] = val;
+                Ok(Node::Leaf(copy))
+            }
+        }
+    }
+
+    /// Iterate over the values in order
+    pub fn iter(&self) -> impl Iterator<Item=&T> + '_ {
+        (0..self.len).filter_map(move |idx| self.get(idx))
+    }
+
+    pub fn len(&self) -> usize { self.len }
+
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+}
+
+// Versions share their nodes, so cloning is O(1)
+impl<T> Clone for PersistentArray<T> {
+    fn clone(&self) -> Self {
+        PersistentArray { root: Arc::clone(&self.root), depth: self.depth, len: self.len }
+    }
+}
@@ -0,0 +1,98 @@
+use {
+    crate::array::Array,
+    std::{
+        hash::Hasher,
+        io::{ self, IoSliceMut, Read },
+        mem::size_of_val,
+        slice::{ from_raw_parts, from_raw_parts_mut },
+    },
+};
+
+/// "Plain old data", types that can be viewed as raw bytes and back
+///
+/// # Safety
+/// Implementors must have no padding bytes, and every bit pattern of the
+/// right size must be a valid value
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($t:ty),*) => { $(unsafe impl Pod for $t {})* };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// Lookup table for the reflected IEEE CRC-32 polynomial
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+// Raw byte access for plain old data
+impl<T: Pod> Array<T> {
+
+    /// View the contents of `self` as raw bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        let elems = self.as_slice();
+        unsafe {
+            // `T: Pod` has no padding, so every byte is initialized
+            from_raw_parts(elems.as_ptr() as *const u8, size_of_val(elems))
+        }
+    }
+
+    /// View the contents of `self` as mutable raw bytes
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        let elems = self.as_mut_slice();
+        unsafe {
+            // `T: Pod` accepts any bit pattern, so any bytes written are valid
+            from_raw_parts_mut(elems.as_mut_ptr() as *mut u8, size_of_val(elems))
+        }
+    }
+
+    /// Fill `self` with exactly as many bytes as it holds from `reader`
+    ///
+    /// Fails with `ErrorKind::UnexpectedEof` if `reader` ends first, leaving
+    /// `self` partly overwritten
+    pub fn read_exact_into<R>(&mut self, reader: &mut R) -> io::Result<()>
+    where R: Read + ?Sized
+    {
+        reader.read_exact(self.as_bytes_mut())
+    }
+
+    /// Read from `reader` into the raw bytes of each of `arrays` in turn,
+    /// with a single call to [`Read::read_vectored`]
+    ///
+    /// Returns the number of bytes read, which like `read_vectored` may be
+    /// fewer than the arrays hold
+    pub fn read_vectored_into<R>(reader: &mut R, arrays: &mut [&mut Array<T>]) -> io::Result<usize>
+    where R: Read + ?Sized
+    {
+        let mut bufs: Vec<IoSliceMut> = arrays.iter_mut().map(|arr| IoSliceMut::new(arr.as_bytes_mut())).collect();
+        reader.read_vectored(&mut bufs)
+    }
+
+    /// The CRC-32 (IEEE) checksum of the raw bytes of `self`
+    pub fn crc32(&self) -> u32 {
+        !self.as_bytes().iter().fold(!0u32, |crc, &b| {
+            CRC32_TABLE[((crc ^ b as u32) & 0xff) as usize] ^ (crc >> 8)
+        })
+    }
+
+    /// Feed the raw bytes of `self` through `hasher` and return its digest
+    pub fn hash_with<H: Hasher>(&self, mut hasher: H) -> u64 {
+        hasher.write(self.as_bytes());
+        hasher.finish()
+    }
+}
@@ -0,0 +1,58 @@
+use std::{
+    collections::BTreeMap,
+    sync::{ Mutex, PoisonError },
+};
+
+/// The byte written over slots whose value has been removed by
+/// [`Array::pop`](crate::array::Array::pop)
+///
+/// Removed slots are also recorded, and [`Array::get`](crate::array::Array::get)
+/// and [`Array::get_checked`](crate::array::Array::get_checked) refuse to read
+/// them until [`Array::set`](crate::array::Array::set) or
+/// [`Array::fill`](crate::array::Array::fill) writes them again. Indexing,
+/// slices and iterators aren't checked, and see the poison bytes
+pub const POISON: u8 = 0xDD;
+
+// A bitmap of removed slots for each buffer holding any, keyed by its address
+static REMOVED: Mutex<BTreeMap<usize, Vec<u64>>> = Mutex::new(BTreeMap::new());
+
+fn with_removed<R>(f: impl FnOnce(&mut BTreeMap<usize, Vec<u64>>) -> R) -> R {
+    // The map is never left half updated, so a panic elsewhere can't break it
+    f(&mut REMOVED.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// Overwrite the `count` slots at `ptr` with [`POISON`]
+pub(crate) unsafe fn poison<T>(ptr: *mut T, count: usize) {
+    ptr.write_bytes(POISON, count);
+}
+
+/// Record that the value at `idx` in the `cap` slot buffer at `buf` was removed
+pub(crate) fn mark_removed(buf: usize, cap: usize, idx: usize) {
+    with_removed(|removed| {
+        let bits = removed.entry(buf).or_insert_with(|| vec![0; cap.div_ceil(64)]);
+        bits[idx / 64] |= 1 << (idx % 64);
+    });
+}
+
+/// Record that the slot at `idx` in the buffer at `buf` holds a value again
+pub(crate) fn mark_live(buf: usize, idx: usize) {
+    with_removed(|removed| {
+        if let Some(bits) = removed.get_mut(&buf) {
+            bits[idx / 64] &= !(1 << (idx % 64));
+        }
+    });
+}
+
+/// Whether the value at `idx` in the buffer at `buf` was removed
+pub(crate) fn is_removed(buf: usize, idx: usize) -> bool {
+    with_removed(|removed| match removed.get(&buf) {
+        Some(bits) => bits[idx / 64] & (1 << (idx % 64)) != 0,
+        None => false,
+    })
+}
+
+/// Forget every removed slot in the buffer at `buf`, when it's freed, given
+/// away or entirely rewritten
+pub(crate) fn forget(buf: usize) {
+    with_removed(|removed| removed.remove(&buf));
+}
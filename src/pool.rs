@@ -0,0 +1,101 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        cell::{ Cell, UnsafeCell },
+        ops::{ Deref, DerefMut },
+    },
+};
+
+/// A fixed set of reusable objects, handed out one at a time by [`Pool::acquire`]
+pub struct Pool<T> {
+    objects: Array<UnsafeCell<T>>,
+    // Stack of the indices of objects not checked out, `free[..available]`
+    free: Array<Cell<usize>>,
+    available: Cell<usize>,
+} impl<T> Pool<T> {
+    /// Create a new Pool of `cap` objects, each made by `init`
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new<F>(cap: usize, mut init: F) -> Result<Self, ArrayError>
+    where F: FnMut() -> T
+    {
+        Self::from_array(Array::from_iter_exact(cap, (0..cap).map(|_| init()))?)
+    }
+
+    /// Create a new Pool holding the objects in `arr`
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn from_array(arr: Array<T>) -> Result<Self, ArrayError> {
+        let cap = arr.cap();
+        Ok(Pool {
+            // UnsafeCell<T> has the same layout as T
+            objects: unsafe { arr.cast() },
+            free: Array::from_iter_exact(cap, (0..cap).map(Cell::new))?,
+            available: Cell::new(cap),
+        })
+    }
+
+    /// Check out an object, which returns to the pool when the guard drops
+    ///
+    /// Error states:
+    ///  * every object is checked out
+    pub fn acquire(&self) -> Result<PoolGuard<'_, T>, ArrayError> {
+        let available = self.available.get();
+        if available == 0 {
+            return Err(ArrayError::new("pool exhausted"));
+        }
+        self.available.set(available - 1);
+        let idx = self.free.as_slice()[available - 1].get();
+        Ok(PoolGuard { pool: self, idx })
+    }
+
+    /// The number of objects not checked out
+    pub fn available(&self) -> usize { self.available.get() }
+
+    pub fn cap(&self) -> usize { self.objects.cap() }
+}
+
+impl<T> Drop for Pool<T> {
+    fn drop(&mut self) {
+        // No guard can outlive the pool
+        unsafe { self.objects.drop_elements() }
+    }
+}
+
+/// An object checked out of a [`Pool`]
+pub struct PoolGuard<'a, T> {
+    pool: &'a Pool<T>,
+    idx: usize,
+}
+
+impl<T> Deref for PoolGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe {
+            // Only this guard can reach object `idx` until it drops
+            &*self.pool.objects.as_slice()[self.idx].get()
+        }
+    }
+}
+
+impl<T> DerefMut for PoolGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe {
+            &mut *self.pool.objects.as_slice()[self.idx].get()
+        }
+    }
+}
+
+impl<T> Drop for PoolGuard<'_, T> {
+    fn drop(&mut self) {
+        let available = self.pool.available.get();
+        self.pool.free.as_slice()[available].set(self.idx);
+        self.pool.available.set(available + 1);
+    }
+}
@@ -0,0 +1,149 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    pyo3::{
+        exceptions::{ PyBufferError, PyIndexError, PyValueError },
+        ffi,
+        prelude::*,
+    },
+    std::{
+        ffi::{ c_int, c_void, CStr },
+        mem::size_of,
+        ptr::null_mut,
+    },
+};
+
+impl From<ArrayError> for PyErr {
+    fn from(err: ArrayError) -> PyErr {
+        PyValueError::new_err(err.msg().clone())
+    }
+}
+
+/// Fill `view` to describe the `len` elements of `itemsize` bytes at `buf`
+///
+/// The shape and strides live in a box stashed in `view.internal`, freed by
+/// [`release_view`]
+unsafe fn fill_view(
+    view: *mut ffi::Py_buffer,
+    flags: c_int,
+    owner: Bound<'_, PyAny>,
+    buf: *mut c_void,
+    len: usize,
+    itemsize: usize,
+    format: &'static CStr,
+) -> PyResult<()> {
+    if view.is_null() {
+        return Err(PyBufferError::new_err("view is null"));
+    }
+    // [shape, strides]
+    let dims = Box::into_raw(Box::new([len as isize, itemsize as isize]));
+    (*view).obj = owner.into_ptr();
+    (*view).buf = buf;
+    (*view).len = (len * itemsize) as isize;
+    (*view).readonly = 0;
+    (*view).itemsize = itemsize as isize;
+    (*view).format = if flags & ffi::PyBUF_FORMAT == ffi::PyBUF_FORMAT {
+        // Python never writes through `format`
+        format.as_ptr() as *mut _
+    } else {
+        null_mut()
+    };
+    (*view).ndim = 1;
+    (*view).shape = if flags & ffi::PyBUF_ND == ffi::PyBUF_ND { &mut (*dims)[0] } else { null_mut() };
+    (*view).strides = if flags & ffi::PyBUF_STRIDES == ffi::PyBUF_STRIDES { &mut (*dims)[1] } else { null_mut() };
+    (*view).suboffsets = null_mut();
+    (*view).internal = dims as *mut c_void;
+    Ok(())
+}
+
+/// Free what [`fill_view`] allocated
+unsafe fn release_view(view: *mut ffi::Py_buffer) {
+    drop(Box::from_raw((*view).internal as *mut [isize; 2]));
+}
+
+// PyO3 can't export generic types, so each element type gets its own class
+macro_rules! py_array {
+    ($name:ident, $py_name:literal, $t:ty, $format:literal) => {
+        #[doc = concat!("An `Array<", stringify!($t), ">` exported to Python as `", $py_name, "`, ")]
+        #[doc = "implementing the buffer protocol so `memoryview` and NumPy can wrap it without copying"]
+        #[pyclass(name = $py_name)]
+        pub struct $name {
+            arr: Array<$t>,
+        }
+
+        #[pymethods]
+        impl $name {
+            /// Create a new, zeroed array of `len` elements
+            #[new]
+            fn py_new(len: usize) -> PyResult<Self> {
+                Ok($name { arr: Array::new(len)? })
+            }
+
+            fn __len__(&self) -> usize {
+                self.arr.cap()
+            }
+
+            fn __getitem__(&self, idx: usize) -> PyResult<$t> {
+                match self.arr.get(idx) {
+                    Some(&val) => Ok(val),
+                    None => Err(PyIndexError::new_err("index out of range")),
+                }
+            }
+
+            fn __setitem__(&mut self, idx: usize, val: $t) -> PyResult<()> {
+                match self.arr.get_mut(idx) {
+                    Some(slot) => {
+                        *slot = val;
+                        Ok(())
+                    }
+                    None => Err(PyIndexError::new_err("index out of range")),
+                }
+            }
+
+            unsafe fn __getbuffer__(slf: Bound<'_, Self>, view: *mut ffi::Py_buffer, flags: c_int) -> PyResult<()> {
+                let (buf, len) = {
+                    let this = slf.borrow();
+                    (this.arr.as_ptr() as *mut c_void, this.arr.cap())
+                };
+                let format = CStr::from_bytes_with_nul($format).expect("format is nul terminated");
+                fill_view(view, flags, slf.into_any(), buf, len, size_of::<$t>(), format)
+            }
+
+            unsafe fn __releasebuffer__(&self, view: *mut ffi::Py_buffer) {
+                release_view(view);
+            }
+        }
+
+        impl $name {
+            /// Borrow the underlying `Array`
+            pub fn as_array(&self) -> &Array<$t> {
+                &self.arr
+            }
+
+            /// Unwrap the underlying `Array`
+            pub fn into_array(self) -> Array<$t> {
+                self.arr
+            }
+        }
+
+        impl From<Array<$t>> for $name {
+            fn from(arr: Array<$t>) -> Self {
+                $name { arr }
+            }
+        }
+    };
+}
+
+py_array!(PyF64Array, "F64Array", f64, b"d\0");
+py_array!(PyU8Array, "U8Array", u8, b"B\0");
+py_array!(PyI64Array, "I64Array", i64, b"q\0");
+
+/// Add the array classes to `module`, for use from a `#[pymodule]`
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyF64Array>()?;
+    module.add_class::<PyU8Array>()?;
+    module.add_class::<PyI64Array>()?;
+    Ok(())
+}
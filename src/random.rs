@@ -0,0 +1,55 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    rand::Rng,
+};
+
+// Random sampling, under the `rand` feature
+impl<T> Array<T> {
+
+    /// A uniformly random element, or `None` if `self` is empty
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        if self.cap() == 0 {
+            return None;
+        }
+        self.as_slice().get(rng.random_range(0..self.cap()))
+    }
+
+    /// A uniformly random sample of `k` distinct elements (or all of them, if
+    /// `k` is at least the length), taken in one pass by reservoir sampling
+    ///
+    /// The order of the sample is not itself random
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn choose_multiple<R: Rng + ?Sized>(&self, rng: &mut R, k: usize) -> Result<Array<T>, ArrayError>
+    where T: Clone
+    {
+        let k = k.min(self.cap());
+        let elems = self.as_slice();
+        let mut sample = Array::from_iter_exact(k, elems[..k].iter().cloned())?;
+        for (i, val) in elems.iter().enumerate().skip(k) {
+            let j = rng.random_range(0..=i);
+            if j < k {
+                sample.as_mut_slice()[j] = val.clone();
+            }
+        }
+        Ok(sample)
+    }
+
+    /// Move a uniformly random sample of `k` elements, in random order, to the
+    /// front of `self`, returning it and the remaining elements
+    ///
+    /// Only `k` swaps are made, so this is cheap for small `k`
+    pub fn partial_shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R, k: usize) -> (&mut [T], &mut [T]) {
+        let len = self.cap();
+        let k = k.min(len);
+        let elems = self.as_mut_slice();
+        for i in 0..k {
+            elems.swap(i, rng.random_range(i..len));
+        }
+        elems.split_at_mut(k)
+    }
+}
@@ -0,0 +1,76 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        iter::repeat_n,
+    },
+};
+
+/// A run-length encoded array, storing each run of equal elements once
+pub struct RleArray<T> {
+    values: Array<T>,
+    // ends[i] is the logical index one past the end of run i
+    ends: Array<usize>,
+} impl<T: PartialEq + Clone> RleArray<T> {
+    /// Encode the contents of `arr`
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn encode(arr: &Array<T>) -> Result<Self, ArrayError> {
+        let runs = arr.chunk_by(|a, b| a == b);
+        let count = runs.clone().count();
+        let values = Array::from_iter_exact(count, runs.clone().map(|run| run[0].clone()))?;
+        let ends = Array::from_iter_exact(count, runs.scan(0, |end, run| {
+            *end += run.len();
+            Some(*end)
+        }))?;
+        Ok(RleArray { values, ends })
+    }
+
+    /// Expand back into a dense `Array`
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn decode(&self) -> Result<Array<T>, ArrayError> {
+        Array::from_iter_exact(self.len(), self.iter().cloned())
+    }
+}
+
+impl<T> RleArray<T> {
+    /// Get the element at logical index `idx`, in O(log runs)
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        let run = self.ends.as_slice().partition_point(|&end| end <= idx);
+        self.values.as_slice().get(run)
+    }
+
+    /// Iterate over every logical element
+    pub fn iter(&self) -> impl Iterator<Item=&T> {
+        self.runs().flat_map(|(val, len)| repeat_n(val, len))
+    }
+
+    /// Iterate over each run's value and length
+    pub fn runs(&self) -> impl Iterator<Item=(&T, usize)> {
+        let starts = std::iter::once(0).chain(self.ends.as_slice().iter().copied());
+        self.values.as_slice().iter()
+            .zip(self.ends.as_slice().iter().zip(starts))
+            .map(|(val, (end, start))| (val, end - start))
+    }
+
+    /// The number of logical elements
+    pub fn len(&self) -> usize {
+        self.ends.as_slice().last().copied().unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// The number of runs stored
+    pub fn run_count(&self) -> usize { self.values.cap() }
+}
+
+impl<T> Drop for RleArray<T> {
+    fn drop(&mut self) {
+        unsafe { self.values.drop_elements() }
+    }
+}
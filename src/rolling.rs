@@ -0,0 +1,46 @@
+use crate::{
+    array::Array,
+    error::ArrayError,
+};
+
+macro_rules! impl_rolling {
+    ($($t:ty),*) => {$(
+        impl Array<$t> {
+
+            /// Create a new `Array` of the sums of every window of `window`
+            /// consecutive elements
+            ///
+            /// Keeps a running sum, so each step costs the same regardless of `window`
+            ///
+            /// Error states:
+            ///  * `window` is 0 or greater than the length of `self`
+            pub fn rolling_sum(&self, window: usize) -> Result<Array<$t>, ArrayError> {
+                if window == 0 || window > self.cap() {
+                    return Err(ArrayError::new(format!("invalid window {} for length {}", window, self.cap())));
+                }
+                let elems = self.as_slice();
+                let first: $t = elems[..window].iter().sum();
+                let rest = elems[window..].iter().zip(elems).scan(first, |sum, (added, removed)| {
+                    *sum += added - removed;
+                    Some(*sum)
+                });
+                Array::from_iter_exact(self.cap() - window + 1, std::iter::once(first).chain(rest))
+            }
+
+            /// Create a new `Array` of the means of every window of `window`
+            /// consecutive elements
+            ///
+            /// Error states:
+            ///  * see [`Array::rolling_sum`]
+            pub fn rolling_mean(&self, window: usize) -> Result<Array<$t>, ArrayError> {
+                let mut means = self.rolling_sum(window)?;
+                for sum in means.iter_mut() {
+                    *sum /= window as $t;
+                }
+                Ok(means)
+            }
+        }
+    )*};
+}
+
+impl_rolling!(f32, f64);
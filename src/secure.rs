@@ -0,0 +1,52 @@
+use {
+    crate::array::Array,
+    std::ops::{ Deref, DerefMut },
+    zeroize::{ Zeroize, ZeroizeOnDrop },
+};
+
+impl<T: Zeroize> Zeroize for Array<T> {
+    fn zeroize(&mut self) {
+        for val in self.as_mut_slice() {
+            val.zeroize();
+        }
+    }
+}
+
+/// An `Array` that is wiped with zeros before its memory is freed
+pub struct SecureArray<T: Zeroize> {
+    arr: Array<T>,
+} impl<T: Zeroize> SecureArray<T> {
+    /// Take ownership of `arr`, wiping it on drop
+    pub fn new(arr: Array<T>) -> Self {
+        SecureArray { arr }
+    }
+}
+
+impl<T: Zeroize> Deref for SecureArray<T> {
+    type Target = Array<T>;
+
+    fn deref(&self) -> &Array<T> {
+        &self.arr
+    }
+}
+
+impl<T: Zeroize> DerefMut for SecureArray<T> {
+    fn deref_mut(&mut self) -> &mut Array<T> {
+        &mut self.arr
+    }
+}
+
+impl<T: Zeroize> From<Array<T>> for SecureArray<T> {
+    fn from(arr: Array<T>) -> Self {
+        Self::new(arr)
+    }
+}
+
+impl<T: Zeroize> Drop for SecureArray<T> {
+    fn drop(&mut self) {
+        // `zeroize` uses volatile writes, so this can't be optimized away
+        self.arr.zeroize();
+    }
+}
+
+impl<T: Zeroize> ZeroizeOnDrop for SecureArray<T> {}
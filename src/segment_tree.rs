@@ -0,0 +1,144 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        marker::PhantomData,
+        ops::{ Add, Range },
+    },
+};
+
+/// An associative operation with an identity element
+pub trait Monoid<T> {
+    /// The value `x` for which `combine(x, a) == combine(a, x) == a`
+    fn identity() -> T;
+
+    /// Combine two values, `combine(combine(a, b), c) == combine(a, combine(b, c))`
+    fn combine(a: &T, b: &T) -> T;
+}
+
+/// Addition, with an identity of `T::default()`
+pub struct Sum;
+
+impl<T: Copy + Default + Add<Output=T>> Monoid<T> for Sum {
+    fn identity() -> T { T::default() }
+    fn combine(a: &T, b: &T) -> T { *a + *b }
+}
+
+/// The smaller of two values
+pub struct Min;
+
+/// The larger of two values
+pub struct Max;
+
+macro_rules! impl_min_max {
+    ($($t:ty => $lo:expr, $hi:expr;)*) => {$(
+        impl Monoid<$t> for Min {
+            fn identity() -> $t { $hi }
+            fn combine(a: &$t, b: &$t) -> $t { if b < a { *b } else { *a } }
+        }
+
+        impl Monoid<$t> for Max {
+            fn identity() -> $t { $lo }
+            fn combine(a: &$t, b: &$t) -> $t { if b > a { *b } else { *a } }
+        }
+    )*};
+}
+
+impl_min_max! {
+    i8 => i8::MIN, i8::MAX;
+    i16 => i16::MIN, i16::MAX;
+    i32 => i32::MIN, i32::MAX;
+    i64 => i64::MIN, i64::MAX;
+    i128 => i128::MIN, i128::MAX;
+    isize => isize::MIN, isize::MAX;
+    u8 => u8::MIN, u8::MAX;
+    u16 => u16::MIN, u16::MAX;
+    u32 => u32::MIN, u32::MAX;
+    u64 => u64::MIN, u64::MAX;
+    u128 => u128::MIN, u128::MAX;
+    usize => usize::MIN, usize::MAX;
+    f32 => f32::NEG_INFINITY, f32::INFINITY;
+    f64 => f64::NEG_INFINITY, f64::INFINITY;
+}
+
+/// A segment tree answering `Op` queries over ranges in O(log n), with O(log n)
+/// point updates
+pub struct SegmentTree<T, Op: Monoid<T>> {
+    // tree[len + i] is element i, tree[i] combines tree[2i] and tree[2i + 1]
+    tree: Array<T>,
+    len: usize,
+    _op: PhantomData<Op>,
+} impl<T, Op: Monoid<T>> SegmentTree<T, Op> {
+    /// Build a tree over `values`
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(values: &[T]) -> Result<Self, ArrayError>
+    where T: Clone
+    {
+        let len = values.len();
+        let leaves = (0..len).map(|_| Op::identity()).chain(values.iter().cloned());
+        let mut tree = Array::from_iter_exact(len * 2, leaves)?;
+        let nodes = tree.as_mut_slice();
+        for i in (1..len).rev() {
+            nodes[i] = Op::combine(&nodes[2 * i], &nodes[2 * i + 1]);
+        }
+        Ok(SegmentTree { tree, len, _op: PhantomData })
+    }
+
+    /// Set element `idx` to `val`
+    ///
+    /// Error states:
+    ///  * `idx` is not less than the length of the tree
+    pub fn update(&mut self, idx: usize, val: T) -> Result<(), ArrayError> {
+        if idx >= self.len {
+            return Err(ArrayError::new("index out of range"));
+        }
+        let nodes = self.tree.as_mut_slice();
+        let mut i = idx + self.len;
+        nodes[i] = val;
+        while i > 1 {
+            i /= 2;
+            nodes[i] = Op::combine(&nodes[2 * i], &nodes[2 * i + 1]);
+        }
+        Ok(())
+    }
+
+    /// Combine the elements in `range`, in order
+    ///
+    /// Error states:
+    ///  * `range` is reversed or extends past the end of the tree
+    pub fn query(&self, range: Range<usize>) -> Result<T, ArrayError> {
+        if range.start > range.end || range.end > self.len {
+            return Err(ArrayError::new(format!("invalid range {:?} for length {}", range, self.len)));
+        }
+        let nodes = self.tree.as_slice();
+        let (mut l, mut r) = (range.start + self.len, range.end + self.len);
+        let (mut left, mut right) = (Op::identity(), Op::identity());
+        while l < r {
+            if l % 2 == 1 {
+                left = Op::combine(&left, &nodes[l]);
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                right = Op::combine(&nodes[r], &right);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        Ok(Op::combine(&left, &right))
+    }
+
+    pub fn len(&self) -> usize { self.len }
+
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+}
+
+impl<T, Op: Monoid<T>> Drop for SegmentTree<T, Op> {
+    fn drop(&mut self) {
+        unsafe { self.tree.drop_elements() }
+    }
+}
@@ -0,0 +1,116 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        mem::replace,
+    },
+};
+
+/// A handle to a value in a [`Slab`]
+///
+/// Keys go stale once their value is removed, even if the slot is reused
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SlabKey {
+    idx: usize,
+    gen: u64,
+}
+
+enum Slot<T> {
+    Occupied { gen: u64, val: T },
+    Vacant { gen: u64, next_free: Option<usize> },
+}
+
+/// A fixed capacity slot map with generational keys, backed by an `Array`
+pub struct Slab<T> {
+    slots: Array<Slot<T>>,
+    free: Option<usize>,
+    len: usize,
+} impl<T> Slab<T> {
+    /// Create a new, empty Slab with room for `cap` values
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(cap: usize) -> Result<Self, ArrayError> {
+        let slots = (0..cap).map(|idx| Slot::Vacant {
+            gen: 0,
+            next_free: if idx + 1 < cap { Some(idx + 1) } else { None },
+        });
+        Ok(Slab {
+            slots: Array::from_iter_exact(cap, slots)?,
+            free: if cap > 0 { Some(0) } else { None },
+            len: 0,
+        })
+    }
+
+    /// Store `val`, returning the key to look it up with
+    ///
+    /// Error states:
+    ///  * every slot is occupied
+    pub fn insert(&mut self, val: T) -> Result<SlabKey, ArrayError> {
+        let idx = match self.free {
+            Some(idx) => idx,
+            None => return Err(ArrayError::new("slab full")),
+        };
+        let slot = &mut self.slots.as_mut_slice()[idx];
+        let (gen, next_free) = match *slot {
+            Slot::Vacant { gen, next_free } => (gen, next_free),
+            Slot::Occupied { .. } => unreachable!("free list points at an occupied slot"),
+        };
+        *slot = Slot::Occupied { gen, val };
+        self.free = next_free;
+        self.len += 1;
+        Ok(SlabKey { idx, gen })
+    }
+
+    /// Remove and return the value for `key`, or `None` if `key` is stale
+    pub fn remove(&mut self, key: SlabKey) -> Option<T> {
+        let slot = self.slots.as_mut_slice().get_mut(key.idx)?;
+        match slot {
+            Slot::Occupied { gen, .. } if *gen == key.gen => {}
+            _ => return None,
+        }
+        let vacant = Slot::Vacant { gen: key.gen.wrapping_add(1), next_free: self.free };
+        self.free = Some(key.idx);
+        self.len -= 1;
+        match replace(slot, vacant) {
+            Slot::Occupied { val, .. } => Some(val),
+            Slot::Vacant { .. } => unreachable!(),
+        }
+    }
+
+    /// Get a reference to the value for `key`, or `None` if `key` is stale
+    pub fn get(&self, key: SlabKey) -> Option<&T> {
+        match self.slots.as_slice().get(key.idx)? {
+            Slot::Occupied { gen, val } if *gen == key.gen => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Get a mutable reference to the value for `key`, or `None` if `key` is stale
+    pub fn get_mut(&mut self, key: SlabKey) -> Option<&mut T> {
+        match self.slots.as_mut_slice().get_mut(key.idx)? {
+            Slot::Occupied { gen, val } if *gen == key.gen => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Whether `key` refers to a stored value
+    pub fn contains(&self, key: SlabKey) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// The number of stored values
+    pub fn len(&self) -> usize { self.len }
+
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    pub fn cap(&self) -> usize { self.slots.cap() }
+}
+
+impl<T> Drop for Slab<T> {
+    fn drop(&mut self) {
+        unsafe { self.slots.drop_elements() }
+    }
+}
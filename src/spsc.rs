@@ -0,0 +1,120 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::{
+        cell::UnsafeCell,
+        mem::MaybeUninit,
+        sync::atomic::{ AtomicUsize, Ordering },
+    },
+};
+
+/// A bounded single-producer single-consumer queue over a fixed `Array`
+///
+/// Use [`SpscQueue::split`] to hand the two ends to different threads
+pub struct SpscQueue<T> {
+    buf: Array<UnsafeCell<MaybeUninit<T>>>,
+    // Both only ever increase, wrapping; `tail - head` is the length
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+// Only the `Producer` writes slots and `tail`, only the `Consumer` reads
+// slots and writes `head`
+unsafe impl<T: Send> Sync for SpscQueue<T> {}
+
+impl<T> SpscQueue<T> {
+    /// Create a new, empty queue with room for `cap` values
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(cap: usize) -> Result<Self, ArrayError> {
+        Ok(SpscQueue {
+            buf: Array::new(cap)?,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        })
+    }
+
+    /// Split into the producing and consuming ends
+    pub fn split(&mut self) -> (Producer<'_, T>, Consumer<'_, T>) {
+        (Producer { queue: self }, Consumer { queue: self })
+    }
+
+    /// Push `val` onto the back, handing it back if the queue is full
+    pub fn try_push(&mut self, val: T) -> Result<(), T> {
+        Producer { queue: self }.try_push(val)
+    }
+
+    /// Pop a value off the front, if there is one
+    pub fn try_pop(&mut self) -> Option<T> {
+        Consumer { queue: self }.try_pop()
+    }
+
+    /// The number of values queued
+    pub fn len(&self) -> usize {
+        self.tail.load(Ordering::Acquire).wrapping_sub(self.head.load(Ordering::Acquire))
+    }
+
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    pub fn cap(&self) -> usize { self.buf.cap() }
+
+    fn slot(&self, idx: usize) -> *mut MaybeUninit<T> {
+        self.buf.as_slice()[idx % self.buf.cap()].get()
+    }
+}
+
+impl<T> Drop for SpscQueue<T> {
+    fn drop(&mut self) {
+        while self.try_pop().is_some() {}
+    }
+}
+
+/// The pushing end of a [`SpscQueue`]
+pub struct Producer<'a, T> {
+    queue: &'a SpscQueue<T>,
+}
+
+impl<T> Producer<'_, T> {
+    /// Push `val` onto the back, handing it back if the queue is full
+    pub fn try_push(&mut self, val: T) -> Result<(), T> {
+        let q = self.queue;
+        let tail = q.tail.load(Ordering::Relaxed);
+        let head = q.head.load(Ordering::Acquire);
+        if tail.wrapping_sub(head) == q.cap() {
+            return Err(val);
+        }
+        unsafe {
+            // The consumer won't read this slot until `tail` moves past it
+            (*q.slot(tail)).write(val);
+        }
+        q.tail.store(tail.wrapping_add(1), Ordering::Release);
+        Ok(())
+    }
+}
+
+/// The popping end of a [`SpscQueue`]
+pub struct Consumer<'a, T> {
+    queue: &'a SpscQueue<T>,
+}
+
+impl<T> Consumer<'_, T> {
+    /// Pop a value off the front, if there is one
+    pub fn try_pop(&mut self) -> Option<T> {
+        let q = self.queue;
+        let head = q.head.load(Ordering::Relaxed);
+        let tail = q.tail.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+        let val = unsafe {
+            // The producer wrote this slot before moving `tail` past it, and
+            // won't reuse it until `head` moves past it
+            (*q.slot(head)).assume_init_read()
+        };
+        q.head.store(head.wrapping_add(1), Ordering::Release);
+        Some(val)
+    }
+}
@@ -0,0 +1,54 @@
+use std::{
+    fmt,
+    sync::atomic::{ AtomicUsize, Ordering },
+};
+
+static ARRAYS_LIVE: AtomicUsize = AtomicUsize::new(0);
+static BYTES_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// A point-in-time copy of the global allocation counters
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations made by [`alloc`](crate::array::alloc) that have not been freed
+    pub arrays_live: usize,
+    /// Bytes currently held by those allocations
+    pub bytes_allocated: usize,
+    /// Highest value `bytes_allocated` has reached
+    pub peak_bytes: usize,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arrays live: {}, bytes allocated: {}, peak bytes: {}",
+            self.arrays_live, self.bytes_allocated, self.peak_bytes
+        )
+    }
+}
+
+/// Read the current counters
+pub fn snapshot() -> AllocStats {
+    AllocStats {
+        arrays_live: ARRAYS_LIVE.load(Ordering::Relaxed),
+        bytes_allocated: BYTES_ALLOCATED.load(Ordering::Relaxed),
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed),
+    }
+}
+
+/// Reset the peak to the number of bytes currently allocated
+pub fn reset_peak() {
+    PEAK_BYTES.store(BYTES_ALLOCATED.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+pub(crate) fn record_alloc(bytes: usize) {
+    ARRAYS_LIVE.fetch_add(1, Ordering::Relaxed);
+    let now = BYTES_ALLOCATED.fetch_add(bytes, Ordering::Relaxed) + bytes;
+    PEAK_BYTES.fetch_max(now, Ordering::Relaxed);
+}
+
+pub(crate) fn record_dealloc(bytes: usize) {
+    ARRAYS_LIVE.fetch_sub(1, Ordering::Relaxed);
+    BYTES_ALLOCATED.fetch_sub(bytes, Ordering::Relaxed);
+}
@@ -0,0 +1,124 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::iter::StepBy,
+    std::slice::{ Iter, IterMut },
+};
+
+/// Read-only view of every `step`th element of an `Array`
+pub struct StridedView<'a, T> {
+    data: &'a [T],
+    step: usize,
+}
+
+/// Mutable view of every `step`th element of an `Array`
+pub struct StridedViewMut<'a, T> {
+    data: &'a mut [T],
+    step: usize,
+}
+
+/// Number of elements a view with `step` over `data_len` elements holds
+fn strided_len(data_len: usize, step: usize) -> usize {
+    data_len.div_ceil(step)
+}
+
+/// The part of `len` elements a view starting at `start` covers
+fn check_stride(len: usize, start: usize, step: usize) -> Result<usize, ArrayError> {
+    if step == 0 {
+        return Err(ArrayError::new("stride step must be non-zero"));
+    }
+    Ok(start.min(len))
+}
+
+impl<T> Array<T> {
+
+    /// View every `step`th element, beginning at `start`
+    ///
+    /// Error states:
+    ///  * `step` is 0
+    pub fn stride(&self, start: usize, step: usize) -> Result<StridedView<'_, T>, ArrayError> {
+        let start = check_stride(self.cap(), start, step)?;
+        Ok(StridedView { data: &self.as_slice()[start..], step })
+    }
+
+    /// Mutably view every `step`th element, beginning at `start`
+    ///
+    /// Error states:
+    ///  * `step` is 0
+    pub fn stride_mut(&mut self, start: usize, step: usize) -> Result<StridedViewMut<'_, T>, ArrayError> {
+        let start = check_stride(self.cap(), start, step)?;
+        Ok(StridedViewMut { data: &mut self.as_mut_slice()[start..], step })
+    }
+}
+
+impl<'a, T> StridedView<'a, T> {
+
+    /// The number of elements in the view
+    pub fn len(&self) -> usize {
+        strided_len(self.data.len(), self.step)
+    }
+
+    /// Whether the view holds no elements
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Get a reference to the `idx`th element of the view
+    pub fn get(&self, idx: usize) -> Option<&'a T> {
+        self.data.get(idx.checked_mul(self.step)?)
+    }
+
+    /// Iterate over the elements of the view
+    pub fn iter(&self) -> StepBy<Iter<'a, T>> {
+        self.data.iter().step_by(self.step)
+    }
+}
+
+impl<T> StridedViewMut<'_, T> {
+
+    /// The number of elements in the view
+    pub fn len(&self) -> usize {
+        strided_len(self.data.len(), self.step)
+    }
+
+    /// Whether the view holds no elements
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Get a reference to the `idx`th element of the view
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.data.get(idx.checked_mul(self.step)?)
+    }
+
+    /// Get a mutable reference to the `idx`th element of the view
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        self.data.get_mut(idx.checked_mul(self.step)?)
+    }
+
+    /// Set the `idx`th element of the view to `val`
+    ///
+    /// Error states:
+    ///  * `idx` is not less than the length of the view
+    pub fn set(&mut self, idx: usize, val: T) -> Result<(), ArrayError> {
+        match self.get_mut(idx) {
+            Some(slot) => {
+                *slot = val;
+                Ok(())
+            }
+            None => Err(ArrayError::new("index out of range")),
+        }
+    }
+
+    /// Iterate over the elements of the view
+    pub fn iter(&self) -> StepBy<Iter<'_, T>> {
+        self.data.iter().step_by(self.step)
+    }
+
+    /// Mutably iterate over the elements of the view
+    pub fn iter_mut(&mut self) -> StepBy<IterMut<'_, T>> {
+        self.data.iter_mut().step_by(self.step)
+    }
+}
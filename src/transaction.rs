@@ -0,0 +1,59 @@
+use crate::{
+    array::Array,
+    error::ArrayError,
+};
+
+/// Writes staged against an `Array` by [`Array::transaction`], which only
+/// reach it if the transaction succeeds
+pub struct Transaction<'a, T> {
+    arr: &'a Array<T>,
+    // In the order they were made, later writes to an index win
+    staged: Vec<(usize, T)>,
+} impl<T> Transaction<'_, T> {
+    /// Stage setting the value at `idx` to `val`
+    ///
+    /// Error states:
+    ///  * `idx` is not less than the length of the array
+    pub fn set(&mut self, idx: usize, val: T) -> Result<(), ArrayError> {
+        if idx >= self.arr.cap() {
+            return Err(ArrayError::new(format!("index {} out of range for length {}", idx, self.arr.cap())));
+        }
+        self.staged.push((idx, val));
+        Ok(())
+    }
+
+    /// Get the value at `idx` as this transaction sees it, including its
+    /// own staged writes, or `None` if it's out of range
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        match self.staged.iter().rev().find(|(staged, _)| *staged == idx) {
+            Some((_, val)) => Some(val),
+            None => self.arr.get(idx),
+        }
+    }
+
+    /// The number of writes staged so far
+    pub fn staged(&self) -> usize {
+        self.staged.len()
+    }
+}
+
+impl<T> Array<T> {
+
+    /// Run `f` against a [`Transaction`], applying its staged writes to
+    /// `self` all at once if it returns `Ok`, and discarding them otherwise
+    ///
+    /// `self` is untouched until `f` returns, so an early `?` never leaves
+    /// it half updated
+    pub fn transaction<R, E, F>(&mut self, f: F) -> Result<R, E>
+    where F: FnOnce(&mut Transaction<'_, T>) -> Result<R, E>
+    {
+        let mut txn = Transaction { arr: self, staged: Vec::new() };
+        let res = f(&mut txn)?;
+        let staged = txn.staged;
+        for (idx, val) in staged {
+            // Checked by `Transaction::set`
+            self[idx] = val;
+        }
+        Ok(res)
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    array::Array,
+    error::ArrayError,
+};
+
+/// A constraint every element of a [`ValidatedArray`] must meet
+pub trait Validator<T> {
+    /// `Ok` if `val` is allowed, otherwise why not
+    fn validate(&self, val: &T) -> Result<(), String>;
+}
+
+impl<T, F> Validator<T> for F
+where F: Fn(&T) -> Result<(), String>
+{
+    fn validate(&self, val: &T) -> Result<(), String> {
+        self(val)
+    }
+}
+
+/// An `Array` whose elements always satisfy `V`, checked on every write
+pub struct ValidatedArray<T, V: Validator<T>> {
+    arr: Array<T>,
+    validator: V,
+} impl<T, V: Validator<T>> ValidatedArray<T, V> {
+    /// Wrap `arr`, after checking its current elements
+    ///
+    /// Error states:
+    ///  * an element of `arr` violates the constraint
+    pub fn new(arr: Array<T>, validator: V) -> Result<Self, ArrayError> {
+        for (idx, val) in arr.iter().enumerate() {
+            Self::check(&validator, idx, val)?;
+        }
+        Ok(ValidatedArray { arr, validator })
+    }
+
+    fn check(validator: &V, idx: usize, val: &T) -> Result<(), ArrayError> {
+        validator.validate(val).map_err(|why| {
+            ArrayError::new(format!("constraint violation at index {}: {}", idx, why))
+        })
+    }
+
+    /// Set the value at `idx` to `val`, if it's allowed
+    ///
+    /// Error states:
+    ///  * `val` violates the constraint
+    ///  * see [`Array::set`]
+    pub fn set(&mut self, idx: usize, val: T) -> Result<(), ArrayError> {
+        self.arr.get_checked(idx)?;
+        Self::check(&self.validator, idx, &val)?;
+        self.arr[idx] = val;
+        Ok(())
+    }
+
+    /// Fill every slot with `val`, if it's allowed
+    ///
+    /// Error states:
+    ///  * `val` violates the constraint
+    pub fn fill(&mut self, val: T) -> Result<(), ArrayError>
+    where T: Clone
+    {
+        self.validator.validate(&val).map_err(|why| {
+            ArrayError::new(format!("constraint violation in fill value: {}", why))
+        })?;
+        self.arr.as_mut_slice().fill(val);
+        Ok(())
+    }
+
+    /// Get the value at `idx`, or `None` if it's out of range
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.arr.get(idx)
+    }
+
+    /// View the contents as a slice
+    pub fn as_slice(&self) -> &[T] {
+        self.arr.as_slice()
+    }
+
+    /// Unwrap the underlying `Array`
+    pub fn into_array(self) -> Array<T> {
+        self.arr
+    }
+}
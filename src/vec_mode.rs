@@ -0,0 +1,101 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::ptr::{ drop_in_place, read, write },
+};
+
+/// A Vec-like view of an `Array`, holding up to `cap` elements without ever
+/// reallocating
+pub struct VecMode<T> {
+    arr: Array<T>,
+    len: usize,
+} impl<T> VecMode<T> {
+    /// Create a new, empty VecMode with room for `cap` elements
+    ///
+    /// Error states:
+    ///  * see [`Array::new`]
+    pub fn new(cap: usize) -> Result<Self, ArrayError> {
+        Ok(VecMode { arr: Array::new(cap)?, len: 0 })
+    }
+
+    /// Append `val` to the end
+    ///
+    /// Error states:
+    ///  * `self` is full
+    pub fn push(&mut self, val: T) -> Result<(), ArrayError> {
+        if self.is_full() {
+            return Err(ArrayError::new("capacity full"));
+        }
+        unsafe {
+            // len < cap
+            write(self.arr.as_ptr().add(self.len), val);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Remove and return the last element, if any
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            // The slot at `len` was initialized and is now outside the live prefix
+            let val = read(self.arr.as_ptr().add(self.len));
+            // Slots past `len` are never read, so any bytes may go there
+            #[cfg(feature = "poison")]
+            crate::poison::poison(self.arr.as_ptr().add(self.len), 1);
+            Some(val)
+        }
+    }
+
+    /// Drop every element past the first `len`
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let tail = &mut self.arr.as_mut_slice()[len..self.len] as *mut [T];
+        // Shorten first so a panicking drop can't cause a double drop
+        #[cfg(feature = "poison")]
+        let removed = self.len - len;
+        self.len = len;
+        unsafe {
+            drop_in_place(tail);
+            #[cfg(feature = "poison")]
+            crate::poison::poison(tail as *mut T, removed);
+        }
+    }
+
+    /// Drop every element
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// The number of elements pushed
+    pub fn len(&self) -> usize { self.len }
+
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    pub fn is_full(&self) -> bool { self.len == self.arr.cap() }
+
+    pub fn cap(&self) -> usize { self.arr.cap() }
+
+    /// View the pushed elements as a slice
+    pub fn as_slice(&self) -> &[T] {
+        &self.arr.as_slice()[..self.len]
+    }
+
+    /// View the pushed elements as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.arr.as_mut_slice()[..self.len]
+    }
+}
+
+impl<T> Drop for VecMode<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
@@ -0,0 +1,130 @@
+use {
+    crate::{
+        array::Array,
+        error::ArrayError,
+    },
+    std::mem::{ replace, take },
+};
+
+enum Op<T> {
+    Set { idx: usize, old: T, new: T },
+    Fill { old: Vec<T>, new: T },
+    Pop { idx: usize, old: T },
+}
+
+/// An `Array` that records every `set`, `fill` and `pop` so they can be
+/// undone and redone
+///
+/// Operations are grouped between calls to [`VersionedArray::checkpoint`],
+/// and each `undo` or `redo` steps over one group
+pub struct VersionedArray<T> {
+    arr: Array<T>,
+    // Finished groups, oldest first
+    undo: Vec<Vec<Op<T>>>,
+    // Operations since the last checkpoint
+    pending: Vec<Op<T>>,
+    // Undone groups, most recently undone last
+    redo: Vec<Vec<Op<T>>>,
+} impl<T: Clone> VersionedArray<T> {
+    /// Start recording changes to `arr`
+    pub fn new(arr: Array<T>) -> Self {
+        VersionedArray { arr, undo: Vec::new(), pending: Vec::new(), redo: Vec::new() }
+    }
+
+    /// Set the value at `idx` to `val`
+    ///
+    /// Error states:
+    ///  * see [`Array::get_checked`]
+    pub fn set(&mut self, idx: usize, val: T) -> Result<(), ArrayError> {
+        self.arr.get_checked(idx)?;
+        let old = replace(&mut self.arr[idx], val.clone());
+        self.record(Op::Set { idx, old, new: val });
+        Ok(())
+    }
+
+    /// Fill every slot with `val`
+    pub fn fill(&mut self, val: T) {
+        let old = self.arr.as_slice().to_vec();
+        self.arr.as_mut_slice().fill(val.clone());
+        self.record(Op::Fill { old, new: val });
+    }
+
+    /// Delete and return the value at `idx`, see [`Array::pop`]
+    ///
+    /// Error states:
+    ///  * see [`Array::pop`]
+    pub fn pop(&mut self, idx: usize) -> Result<T, ArrayError> {
+        let val = self.arr.pop(idx)?;
+        self.record(Op::Pop { idx, old: val.clone() });
+        Ok(val)
+    }
+
+    /// End the current group of operations, so the next `undo` stops here
+    pub fn checkpoint(&mut self) {
+        if !self.pending.is_empty() {
+            let group = take(&mut self.pending);
+            self.undo.push(group);
+        }
+    }
+
+    /// Revert the most recent group of operations, returning whether there was one
+    pub fn undo(&mut self) -> bool {
+        self.checkpoint();
+        let group = match self.undo.pop() {
+            Some(group) => group,
+            None => return false,
+        };
+        for op in group.iter().rev() {
+            match op {
+                Op::Set { idx, old, .. } => self.arr[*idx] = old.clone(),
+                Op::Fill { old, .. } => self.arr.as_mut_slice().clone_from_slice(old),
+                // The slot was cleared, so there's nothing to drop
+                Op::Pop { idx, old } => {
+                    let _ = self.arr.set(*idx, old.clone());
+                }
+            }
+        }
+        self.redo.push(group);
+        true
+    }
+
+    /// Reapply the most recently undone group, returning whether there was one
+    pub fn redo(&mut self) -> bool {
+        let group = match self.redo.pop() {
+            Some(group) => group,
+            None => return false,
+        };
+        for op in &group {
+            match op {
+                Op::Set { idx, new, .. } => self.arr[*idx] = new.clone(),
+                Op::Fill { new, .. } => self.arr.as_mut_slice().fill(new.clone()),
+                Op::Pop { idx, .. } => {
+                    let _ = self.arr.pop(*idx);
+                }
+            }
+        }
+        self.undo.push(group);
+        true
+    }
+
+    fn record(&mut self, op: Op<T>) {
+        // A new change forks history, the undone groups can't be reached anymore
+        self.redo.clear();
+        self.pending.push(op);
+    }
+
+    /// Get the value at `idx`, or `None` if it's out of range
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.arr.get(idx)
+    }
+
+    /// View the current contents as a slice
+    pub fn as_slice(&self) -> &[T] {
+        self.arr.as_slice()
+    }
+
+    /// Stop recording, returning the underlying `Array`
+    pub fn into_array(self) -> Array<T> {
+        self.arr
+    }
+}
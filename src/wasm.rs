@@ -0,0 +1,71 @@
+use {
+    crate::array::Array,
+    js_sys::{ Float64Array, Uint8Array },
+    wasm_bindgen::prelude::*,
+};
+
+// wasm-bindgen can't export generic types, so each element type gets its own class
+macro_rules! wasm_array {
+    ($name:ident, $js_name:literal, $t:ty, $typed:ident) => {
+        #[doc = concat!("An `Array<", stringify!($t), ">` exported to JavaScript as `", $js_name, "`")]
+        #[wasm_bindgen(js_name = $js_name)]
+        pub struct $name {
+            arr: Array<$t>,
+        }
+
+        #[wasm_bindgen(js_class = $js_name)]
+        impl $name {
+            /// Create a new, zeroed array of `len` elements
+            #[wasm_bindgen(constructor)]
+            pub fn new(len: usize) -> Result<$name, JsError> {
+                Ok($name { arr: Array::new(len).map_err(|e| JsError::new(e.msg()))? })
+            }
+
+            /// Create a new array holding a copy of `typed`
+            #[wasm_bindgen(js_name = fromTypedArray)]
+            pub fn from_typed_array(typed: &$typed) -> Result<$name, JsError> {
+                let mut arr = $name::new(typed.length() as usize)?;
+                typed.copy_to(arr.arr.as_mut_slice());
+                Ok(arr)
+            }
+
+            /// The number of elements
+            #[wasm_bindgen(getter)]
+            pub fn length(&self) -> usize {
+                self.arr.cap()
+            }
+
+            /// A typed array viewing the elements in WASM linear memory, without copying
+            ///
+            /// The view is invalidated if linear memory grows, so take a new
+            /// one after anything that may allocate
+            pub fn view(&self) -> $typed {
+                unsafe {
+                    // The view is only as long-lived as the caller keeps it, see above
+                    $typed::view(self.arr.as_slice())
+                }
+            }
+        }
+
+        impl $name {
+            /// Borrow the underlying `Array`
+            pub fn as_array(&self) -> &Array<$t> {
+                &self.arr
+            }
+
+            /// Mutably borrow the underlying `Array`
+            pub fn as_array_mut(&mut self) -> &mut Array<$t> {
+                &mut self.arr
+            }
+        }
+
+        impl From<Array<$t>> for $name {
+            fn from(arr: Array<$t>) -> Self {
+                $name { arr }
+            }
+        }
+    };
+}
+
+wasm_array!(WasmU8Array, "U8Array", u8, Uint8Array);
+wasm_array!(WasmF64Array, "F64Array", f64, Float64Array);
@@ -4,6 +4,8 @@ use std::{
     ptr::NonNull,
 };
 
+// Kept as written before the lints were enforced
+#[allow(unused_variables, unused_imports, unused_mut)]
 #[test]
 fn alloc_test() -> Result<(), ArrayError> {
     let addr: NonNull<u8> = alloc::<u8>(32)?;
@@ -17,6 +19,7 @@ fn alloc_test() -> Result<(), ArrayError> {
     Ok(())
 }
 
+#[allow(unused_variables, unused_imports, unused_mut)]
 #[test]
 fn array_test() -> Result<(), ArrayError> {
     use std::ptr::copy;
@@ -29,5 +32,2027 @@ fn array_test() -> Result<(), ArrayError> {
     eprintln!("{:?} -> {:?}", arr, arr.clone());
 
 
+    Ok(())
+}
+
+#[cfg(feature = "stats")]
+#[test]
+fn stats_test() -> Result<(), ArrayError> {
+    use better_array::stats;
+
+    // Large enough to stand out from other tests allocating in parallel
+    let arrs = (0..1000).map(|_| Array::<u64>::new(1024)).collect::<Result<Vec<_>, ArrayError>>()?;
+    let during = stats::snapshot();
+    assert!(during.arrays_live >= 1000);
+    assert!(during.bytes_allocated >= 1000 * 1024 * 8);
+    assert!(during.peak_bytes >= during.bytes_allocated);
+    drop(arrs);
+
+    let after = stats::snapshot();
+    assert!(after.arrays_live + 500 <= during.arrays_live);
+    assert!(after.bytes_allocated + 500 * 1024 * 8 <= during.bytes_allocated);
+
+    Ok(())
+}
+
+#[test]
+fn leak_test() -> Result<(), ArrayError> {
+    let mut arr = Array::<u16>::new(4)?;
+    arr.fill(7);
+    let table: &'static mut [u16] = arr.leak();
+    table[3] = 9;
+    assert_eq!(table, &[7, 7, 7, 9]);
+
+    Ok(())
+}
+
+#[test]
+fn boxed_slice_test() -> Result<(), ArrayError> {
+    let mut arr = Array::<u32>::new(3)?;
+    arr.fill(5);
+    #[cfg(not(feature = "debug-guard"))]
+    let ptr = arr.as_ptr();
+
+    let boxed: Box<[u32]> = arr.into_boxed_slice();
+    // Guarded allocations are moved rather than handed over
+    #[cfg(not(feature = "debug-guard"))]
+    assert_eq!(boxed.as_ptr(), ptr as *const u32);
+    assert_eq!(&boxed[..], &[5, 5, 5]);
+
+    let back = Array::from_boxed_slice(boxed);
+    #[cfg(not(feature = "debug-guard"))]
+    assert_eq!(back.as_ptr(), ptr);
+    assert_eq!(back[2], 5);
+
+    let empty = Array::from_boxed_slice(Vec::<u32>::new().into_boxed_slice());
+    assert_eq!(empty.cap(), 0);
+    assert_eq!(Box::<[u32]>::from(empty).len(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn as_ref_borrow_test() -> Result<(), ArrayError> {
+    use std::collections::HashMap;
+
+    fn total<A: AsRef<[u8]>>(a: A) -> u32 {
+        a.as_ref().iter().map(|&b| b as u32).sum()
+    }
+
+    let mut arr = Array::<u8>::new(4)?;
+    arr.fill(2);
+    arr.as_mut()[0] = 10;
+    assert_eq!(total(&arr), 16);
+
+    let mut map = HashMap::new();
+    map.insert(arr, "key");
+    assert_eq!(map.get(&[10u8, 2, 2, 2][..]), Some(&"key"));
+    assert_eq!(map.get(&[10u8, 2, 2][..]), None);
+
+    Ok(())
+}
+
+#[test]
+fn from_iter_exact_test() -> Result<(), ArrayError> {
+    let arr = Array::from_iter_exact(4, 1..=4u8)?;
+    assert_eq!(arr.as_slice(), &[1, 2, 3, 4]);
+
+    assert!(Array::from_iter_exact(4, 1..4u8).is_err());
+    assert!(Array::from_iter_exact(4, 1..6u8).is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_test() -> Result<(), ArrayError> {
+    let mut arr = Array::<u32>::new(10_000)?;
+    arr.par_fill(3);
+    assert_eq!(arr.count(3), 10_000);
+
+    let doubled = arr.par_map(|v| *v as u64 * 2)?;
+    assert!(doubled.as_slice().iter().all(|&v| v == 6));
+
+    arr.par_fill_with(|| 1);
+    assert_eq!(arr.count(1), 10_000);
+
+    // `Cell` is `Send + Clone` but not `Sync`
+    let mut cells = Array::<std::cell::Cell<u8>>::new(100)?;
+    cells.par_fill(std::cell::Cell::new(5));
+    assert!(cells.iter().all(|c| c.get() == 5));
+
+    Ok(())
+}
+
+#[test]
+fn stride_test() -> Result<(), ArrayError> {
+    // Interleaved LRLRLR samples
+    let mut arr = Array::from_iter_exact(6, vec![1, 10, 2, 20, 3, 30])?;
+
+    let right = arr.stride(1, 2)?;
+    assert_eq!(right.len(), 3);
+    assert_eq!(right.iter().copied().collect::<Vec<i32>>(), vec![10, 20, 30]);
+
+    let mut left = arr.stride_mut(0, 2)?;
+    left.iter_mut().for_each(|v| *v *= -1);
+    left.set(2, 0)?;
+    assert!(left.set(3, 0).is_err());
+    assert_eq!(arr.as_slice(), &[-1, 10, -2, 20, 0, 30]);
+
+    assert!(arr.stride(0, 0).is_err());
+    assert!(arr.stride(9, 2)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn cast_test() -> Result<(), ArrayError> {
+    let mut arr = Array::<u32>::new(2)?;
+    arr.fill(u32::MAX);
+    let ptr = arr.as_ptr();
+
+    let signed = unsafe { arr.cast::<i32>() };
+    assert_eq!(signed.as_ptr() as *mut u32, ptr);
+    assert_eq!(signed.as_slice(), &[-1, -1]);
+
+    let bytes = Array::<[u8; 4]>::new(2)?;
+    let words = unsafe { bytes.try_cast::<u16>() };
+    assert!(words.is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn encoding_test() -> Result<(), ArrayError> {
+    let arr = Array::from_iter_exact(5, b"hello".iter().copied())?;
+    assert_eq!(arr.to_hex(), "68656c6c6f");
+    assert_eq!(Array::from_hex("68656C6c6f")?, arr);
+    assert!(Array::from_hex("686").is_err());
+    assert!(Array::from_hex("zz").is_err());
+
+    assert_eq!(arr.to_base64(), "aGVsbG8=");
+    assert_eq!(Array::from_base64("aGVsbG8=")?, arr);
+    for s in ["", "Zg==", "Zm8=", "Zm9v"] {
+        assert_eq!(Array::from_base64(s)?.to_base64(), s);
+    }
+    assert!(Array::from_base64("aG=sbG8=").is_err());
+    assert!(Array::from_base64("aGVsbG8").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn string_test() -> Result<(), ArrayError> {
+    let bytes: Array<u8> = "héllo".parse()?;
+    assert_eq!(bytes.cap(), 6);
+    assert_eq!(bytes.from_utf8()?, "héllo");
+
+    let mut invalid = Array::<u8>::new(1)?;
+    invalid.fill(0xff);
+    assert!(invalid.from_utf8().is_err());
+
+    let chars: Array<char> = "héllo".parse()?;
+    assert_eq!(chars.cap(), 5);
+    assert_eq!(chars[1], 'é');
+    assert_eq!(String::from(chars), "héllo");
+
+    Ok(())
+}
+
+#[test]
+fn find_subarray_test() -> Result<(), ArrayError> {
+    let packet: Array<u8> = "GET / HTTP/1.1\r\n\r\nbody".parse()?;
+    assert_eq!(packet.find_subarray(b"\r\n\r\n"), Some(14));
+    assert_eq!(packet.find_subarray(b"POST"), None);
+    assert_eq!(packet.find_subarray(b""), Some(0));
+
+    Ok(())
+}
+
+#[test]
+fn starts_ends_with_test() -> Result<(), ArrayError> {
+    let arr = Array::from_iter_exact(4, 1..=4)?;
+    let head = Array::from_iter_exact(2, 1..=2)?;
+    assert!(arr.starts_with(&head));
+    assert!(arr.starts_with([1, 2, 3]));
+    assert!(!arr.starts_with([2]));
+    assert!(arr.ends_with(&[3, 4][..]));
+    assert!(!arr.ends_with(&head));
+
+    Ok(())
+}
+
+#[test]
+fn split_test() -> Result<(), ArrayError> {
+    let records: Array<u8> = "a,bc,,d".parse()?;
+    let fields: Vec<&[u8]> = records.split(|&b| b == b',').collect();
+    assert_eq!(fields, vec![&b"a"[..], b"bc", b"", b"d"]);
+
+    Ok(())
+}
+
+#[test]
+fn chunk_by_test() -> Result<(), ArrayError> {
+    // Timestamps in hours, grouped by day
+    let stamps = Array::from_iter_exact(6, vec![1u32, 5, 23, 25, 40, 49])?;
+    let days: Vec<&[u32]> = stamps.chunk_by(|a, b| a / 24 == b / 24).collect();
+    assert_eq!(days, vec![&[1, 5, 23][..], &[25, 40], &[49]]);
+
+    Ok(())
+}
+
+#[test]
+fn partition_test() -> Result<(), ArrayError> {
+    let mut arr = Array::from_iter_exact(7, vec![5, 2, 8, 1, 4, 7, 6])?;
+
+    let (even, odd) = arr.partition(|v| v % 2 == 0);
+    assert_eq!(even.as_slice(), &[2, 8, 4, 6]);
+    assert_eq!(odd.as_slice(), &[5, 1, 7]);
+
+    let (none, all) = arr.partition(|_| false);
+    assert_eq!(none.cap(), 0);
+    assert_eq!(all, arr);
+
+    let mut calls = 0;
+    let (first_three, rest) = arr.partition(|_| {
+        calls += 1;
+        calls <= 3
+    });
+    assert_eq!((first_three.as_slice(), rest.cap(), calls), (&[5, 2, 8][..], 4, 7));
+
+    let split = arr.partition_in_place(|v| v % 2 == 0);
+    assert_eq!(split, 4);
+    assert_eq!(arr.as_slice(), &[2, 8, 4, 6, 5, 1, 7]);
+
+    Ok(())
+}
+
+#[test]
+fn flatten_test() -> Result<(), ArrayError> {
+    let chunks = Array::from_iter_exact(3, vec![
+        Array::from_iter_exact(2, 1..=2)?,
+        Array::new(0)?,
+        Array::from_iter_exact(3, 3..=5)?,
+    ])?;
+    let flat = chunks.flatten()?;
+    assert_eq!(flat.as_slice(), &[1, 2, 3, 4, 5]);
+
+    Ok(())
+}
+
+#[test]
+fn repeat_test() -> Result<(), ArrayError> {
+    let period = Array::from_iter_exact(3, vec![0, 1, 0])?;
+    assert_eq!(period.repeat(3)?.as_slice(), &[0, 1, 0, 0, 1, 0, 0, 1, 0]);
+    assert_eq!(period.repeat(0)?.cap(), 0);
+    assert!(period.repeat(usize::MAX).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn interleave_test() -> Result<(), ArrayError> {
+    let left = Array::from_iter_exact(3, vec![1, 2, 3])?;
+    let right = Array::from_iter_exact(3, vec![-1, -2, -3])?;
+    let packed = left.interleave(&right)?;
+    assert_eq!(packed.as_slice(), &[1, -1, 2, -2, 3, -3]);
+
+    let channels = packed.deinterleave(2)?;
+    assert_eq!(channels, [left, right]);
+
+    assert!(packed.deinterleave(4).is_err());
+    assert!(packed.deinterleave(0).is_err());
+    assert!(packed.interleave(&channels[0]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn ct_eq_test() -> Result<(), ArrayError> {
+    let mac: Array<u8> = "0123456789abcdef".parse()?;
+    assert!(mac.ct_eq(&"0123456789abcdef".parse()?));
+    assert!(!mac.ct_eq(&"0123456789abcdeF".parse()?));
+    assert!(!mac.ct_eq(&"0123".parse()?));
+
+    Ok(())
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize_test() -> Result<(), ArrayError> {
+    use better_array::secure::SecureArray;
+    use zeroize::Zeroize;
+
+    let mut key = Array::<u8>::new(32)?;
+    key.fill(0xaa);
+    key.zeroize();
+    assert_eq!(key.count(0), 32);
+
+    let mut secret = SecureArray::new(key);
+    secret.fill(0x55);
+    assert_eq!(secret[0], 0x55);
+
+    Ok(())
+}
+
+#[test]
+fn bounds_test() -> Result<(), ArrayError> {
+    let mut arr = Array::<u8>::new(4)?;
+    assert!(arr.get(3).is_some());
+    assert!(arr.get(4).is_none());
+    assert!(arr.set(4, 1).is_err());
+    assert!(arr.pop(4).is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "debug-guard")]
+#[test]
+fn debug_guard_test() -> Result<(), ArrayError> {
+    let mut arr = Array::<u32>::new(4)?;
+    arr.fill(1);
+    arr.check_guards()?;
+
+    unsafe {
+        // An off-by-one write just past the buffer
+        let past = arr.as_ptr().add(4);
+        let saved = past.read();
+        past.write(0);
+        assert!(arr.check_guards().is_err());
+        assert!(arr.get_checked(0).is_err());
+        past.write(saved);
+    }
+    arr.check_guards()?;
+
+    let boxed = arr.into_boxed_slice();
+    assert_eq!(Array::from_boxed_slice(boxed).as_slice(), &[1, 1, 1, 1]);
+
+    Ok(())
+}
+
+#[test]
+fn unchecked_test() -> Result<(), ArrayError> {
+    let mut arr = Array::<u64>::new(8)?;
+    unsafe {
+        for idx in 0..8 {
+            arr.set_unchecked(idx, idx as u64 * 3);
+        }
+        *arr.get_unchecked_mut(0) = 100;
+        assert_eq!(*arr.get_unchecked(7), 21);
+        assert_eq!(*arr.get_unchecked(0), 100);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn indexed_test() -> Result<(), ArrayError> {
+    #[derive(Clone, Copy)]
+    struct NodeId(usize);
+
+    impl ArrayIndex for NodeId {
+        fn from_index(idx: usize) -> Self { NodeId(idx) }
+        fn index(self) -> usize { self.0 }
+    }
+
+    let mut weights = IndexedArray::<NodeId, f32>::new(3)?;
+    for node in weights.indices().collect::<Vec<NodeId>>() {
+        weights.set(node, node.0 as f32 * 0.5)?;
+    }
+    assert_eq!(weights[NodeId(2)], 1.0);
+    assert!(weights.get(NodeId(3)).is_none());
+    assert_eq!(weights.into_array().as_slice(), &[0.0, 0.5, 1.0]);
+
+    Ok(())
+}
+
+better_array::enum_index! {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Op { Add, Sub, Mul }
+}
+
+#[test]
+fn enum_array_test() -> Result<(), ArrayError> {
+    let mut costs = EnumArray::<Op, u32>::filled(1)?;
+    costs[Op::Mul] = 3;
+    assert_eq!(costs[Op::Add], 1);
+    assert_eq!(costs[Op::Mul], 3);
+
+    let names = EnumArray::<Op, String>::from_fn(|op| format!("{:?}", op))?;
+    let all: Vec<(Op, &String)> = names.iter().collect();
+    assert_eq!(all[1], (Op::Sub, &"Sub".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn vec_mode_test() -> Result<(), ArrayError> {
+    let mut v = VecMode::<String>::new(3)?;
+    assert!(v.is_empty());
+    v.push("a".to_string())?;
+    v.push("b".to_string())?;
+    v.push("c".to_string())?;
+    assert!(v.push("d".to_string()).is_err());
+    assert_eq!(v.len(), 3);
+
+    assert_eq!(v.pop_back(), Some("c".to_string()));
+    v.truncate(1);
+    assert_eq!(v.as_slice(), &["a".to_string()]);
+    v.clear();
+    assert_eq!(v.pop_back(), None);
+
+    Ok(())
+}
+
+#[test]
+fn slab_test() -> Result<(), ArrayError> {
+    let mut entities = Slab::<String>::new(2)?;
+    let a = entities.insert("a".to_string())?;
+    let b = entities.insert("b".to_string())?;
+    assert!(entities.insert("c".to_string()).is_err());
+
+    assert_eq!(entities.remove(a), Some("a".to_string()));
+    assert_eq!(entities.remove(a), None);
+    let c = entities.insert("c".to_string())?;
+    // `c` reuses the slot `a` had, but `a` stays stale
+    assert!(entities.get(a).is_none());
+    assert_eq!(entities.get(c).map(String::as_str), Some("c"));
+
+    entities.get_mut(b).unwrap().push('!');
+    assert_eq!(entities.get(b).map(String::as_str), Some("b!"));
+    assert_eq!(entities.len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn arena_test() -> Result<(), ArrayError> {
+    let mut arena = Arena::new(64)?;
+    let byte = arena.alloc(1u8)?;
+    let word = arena.alloc(0xdead_beef_u64)?;
+    *byte += 1;
+    assert_eq!((*byte, *word), (2, 0xdead_beef));
+    assert_eq!(word as *mut u64 as usize % 8, 0);
+
+    let floats = arena.alloc_slice_copy(&[1.0f32, 2.0, 3.0])?;
+    floats[0] = 0.5;
+    assert_eq!(floats, &[0.5, 2.0, 3.0]);
+    assert!(arena.alloc([0u8; 64]).is_err());
+
+    arena.reset();
+    assert_eq!(arena.used(), 0);
+    assert!(arena.alloc([0u8; 64]).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn pool_test() -> Result<(), ArrayError> {
+    let pool = Pool::new(2, || Vec::<u8>::with_capacity(16))?;
+    {
+        let mut a = pool.acquire()?;
+        let mut b = pool.acquire()?;
+        assert!(pool.acquire().is_err());
+        a.push(1);
+        b.extend_from_slice(&[2, 3]);
+        assert_eq!(pool.available(), 0);
+    }
+    assert_eq!(pool.available(), 2);
+
+    // Objects come back as they were left
+    let (a, b) = (pool.acquire()?, pool.acquire()?);
+    assert_eq!(a.len() + b.len(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn array_string_test() -> Result<(), ArrayError> {
+    use std::fmt::Write;
+
+    let mut s = ArrayString::new(8)?;
+    s.push_str("héllo")?;
+    s.push('!')?;
+    assert_eq!(&*s, "héllo!");
+    assert_eq!(s.remaining(), 1);
+    assert!(s.push('é').is_err());
+    assert!(s.push_str("ab").is_err());
+    assert_eq!(s.len(), 7);
+
+    s.clear();
+    assert!(s.try_push("ab¢def¢g").is_err());
+    assert_eq!(s.to_string(), "ab¢def");
+
+    s.clear();
+    assert!(write!(s, "{}-{}", 12, 34).is_ok());
+    assert_eq!(format!("{:?}", s), "\"12-34\"");
+
+    Ok(())
+}
+
+#[test]
+fn spsc_test() -> Result<(), ArrayError> {
+    let mut queue = SpscQueue::<u64>::new(4)?;
+    assert!(queue.try_push(1).is_ok());
+    assert_eq!(queue.try_pop(), Some(1));
+
+    let (mut tx, mut rx) = queue.split();
+    let sum = std::thread::scope(|s| {
+        s.spawn(move || {
+            for i in 0..10_000 {
+                let mut val = i;
+                while let Err(v) = tx.try_push(val) {
+                    val = v;
+                    std::thread::yield_now();
+                }
+            }
+        });
+        let mut sum = 0;
+        let mut expect = 0;
+        while expect < 10_000 {
+            match rx.try_pop() {
+                Some(v) => {
+                    assert_eq!(v, expect);
+                    sum += v;
+                    expect += 1;
+                }
+                None => std::thread::yield_now(),
+            }
+        }
+        sum
+    });
+    assert_eq!(sum, (0..10_000).sum::<u64>());
+    assert!(queue.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn mpmc_test() -> Result<(), ArrayError> {
+    let queue = MpmcQueue::<u64>::new(8)?;
+    assert!(MpmcQueue::<u64>::new(0).is_err());
+    for i in 0..8 {
+        assert!(queue.try_send(i).is_ok());
+    }
+    assert_eq!(queue.try_send(8), Err(8));
+    assert_eq!(queue.try_recv(), Some(0));
+
+    let queue = &queue;
+    let total = std::thread::scope(|s| {
+        for t in 0..3 {
+            s.spawn(move || (0..1000).for_each(|i| queue.send(t * 1000 + i + 100)));
+        }
+        // 7 values left over plus 3000 sent
+        let receivers: Vec<_> = [1503, 1504].iter()
+            .map(|&n| s.spawn(move || (0..n).map(|_| queue.recv()).sum::<u64>()))
+            .collect();
+        receivers.into_iter().map(|r| r.join().unwrap()).sum::<u64>()
+    });
+    assert_eq!(total, (1..8).sum::<u64>() + (100..3100).sum::<u64>());
+    assert_eq!(queue.try_recv(), None);
+
+    Ok(())
+}
+
+#[test]
+fn concurrent_test() -> Result<(), ArrayError> {
+    let counters = ConcurrentArray::new(10, 4, 0u32)?;
+    assert_eq!(counters.shard_count(), 4);
+    assert!(ConcurrentArray::new(10, 0, 0u32).is_err());
+
+    std::thread::scope(|s| {
+        for t in 0..4 {
+            let counters = &counters;
+            s.spawn(move || {
+                for i in 0..100 {
+                    counters.update((t + i) % 10, |c| *c += 1).unwrap();
+                }
+            });
+        }
+    });
+    let total: u32 = (0..10).map(|i| counters.get(i).unwrap()).sum();
+    assert_eq!(total, 400);
+
+    counters.set(9, 7)?;
+    assert_eq!(counters.get(9)?, 7);
+    assert!(counters.get(10).is_err());
+
+    let shared = std::sync::Arc::new(());
+    drop(ConcurrentArray::new(10, 3, shared.clone())?);
+    assert_eq!(std::sync::Arc::strong_count(&shared), 1);
+
+    Ok(())
+}
+
+#[test]
+fn lazy_test() -> Result<(), ArrayError> {
+    use std::sync::atomic::{ AtomicUsize, Ordering };
+
+    let memo = LazyArray::<u64>::new(4)?;
+    let calls = AtomicUsize::new(0);
+    assert_eq!(memo.get(2), None);
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                let v = memo.get_or_init(2, || {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    42
+                });
+                assert_eq!(v.unwrap(), &42);
+            });
+        }
+    });
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(memo.get(2), Some(&42));
+    assert!(memo.get_or_init(4, || 0).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn segment_tree_test() -> Result<(), ArrayError> {
+    use better_array::segment_tree::{ Sum, Min, Max };
+
+    let values = [5i64, -2, 7, 3, 0, 9, -4];
+    let mut sums = SegmentTree::<i64, Sum>::new(&values)?;
+    assert_eq!(sums.query(0..7)?, 18);
+    assert_eq!(sums.query(2..5)?, 10);
+    assert_eq!(sums.query(3..3)?, 0);
+    sums.update(1, 10)?;
+    assert_eq!(sums.query(0..2)?, 15);
+    assert!(sums.query(0..8).is_err());
+    assert!(sums.update(7, 0).is_err());
+
+    let mins = SegmentTree::<i64, Min>::new(&values)?;
+    let maxs = SegmentTree::<i64, Max>::new(&values)?;
+    assert_eq!((mins.query(1..6)?, maxs.query(1..6)?), (-2, 9));
+
+    // Non-commutative: string concatenation keeps element order
+    struct Concat;
+    impl Monoid<String> for Concat {
+        fn identity() -> String { String::new() }
+        fn combine(a: &String, b: &String) -> String { format!("{}{}", a, b) }
+    }
+    let letters: Vec<String> = "abcde".chars().map(String::from).collect();
+    let words = SegmentTree::<String, Concat>::new(&letters)?;
+    assert_eq!(words.query(1..5)?, "bcde");
+
+    Ok(())
+}
+
+#[test]
+fn fenwick_test() -> Result<(), ArrayError> {
+    let values = [3i64, 1, 4, 1, 5, 9, 2, 6];
+    let mut tree = FenwickTree::from_slice(&values)?;
+    for end in 0..=values.len() {
+        assert_eq!(tree.prefix_sum(end)?, values[..end].iter().sum::<i64>());
+    }
+    assert_eq!(tree.range_sum(2..6)?, 19);
+
+    tree.add(3, 10)?;
+    assert_eq!(tree.range_sum(3..4)?, 11);
+    assert!(tree.add(8, 1).is_err());
+    assert!(tree.prefix_sum(9).is_err());
+
+    let mut empty = FenwickTree::<f64>::new(4)?;
+    empty.add(0, 0.5)?;
+    assert_eq!(empty.prefix_sum(4)?, 0.5);
+
+    Ok(())
+}
+
+#[test]
+fn rle_test() -> Result<(), ArrayError> {
+    let dense: Array<u8> = "aaaabbbcca".parse()?;
+    let rle = RleArray::encode(&dense)?;
+    assert_eq!(rle.run_count(), 4);
+    assert_eq!(rle.len(), 10);
+    assert_eq!(rle.get(0), Some(&b'a'));
+    assert_eq!(rle.get(4), Some(&b'b'));
+    assert_eq!(rle.get(8), Some(&b'c'));
+    assert_eq!(rle.get(9), Some(&b'a'));
+    assert_eq!(rle.get(10), None);
+    assert_eq!(rle.runs().map(|(_, n)| n).collect::<Vec<usize>>(), vec![4, 3, 2, 1]);
+    assert_eq!(rle.decode()?, dense);
+
+    Ok(())
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn compress_test() -> Result<(), ArrayError> {
+    let data: Array<u8> = "abcabcabcabcabcabcabcabcabcabc".repeat(100).parse()?;
+    let packed = data.compress(6)?;
+    assert!(packed.cap() < data.cap() / 10);
+    assert_eq!(packed.decompress()?, data);
+
+    let stored = data.compress(0)?;
+    assert!(stored.cap() > data.cap());
+    assert_eq!(stored.decompress()?, data);
+
+    assert!(data.compress(10).is_err());
+    assert!(data.decompress().is_err());
+    let truncated: Array<u8> = packed.as_slice()[..packed.cap() / 2].iter().copied().collect();
+    assert!(truncated.decompress().is_err());
+
+    let empty = Array::<u8>::new(0)?;
+    assert_eq!(empty.compress(9)?.decompress()?.cap(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn checksum_test() -> Result<(), ArrayError> {
+    use std::collections::hash_map::DefaultHasher;
+
+    let text: Array<u8> = "123456789".parse()?;
+    assert_eq!(text.crc32(), 0xCBF4_3926);
+
+    let mut words = Array::<u32>::new(2)?;
+    words.fill(0x0403_0201);
+    assert_eq!(&words.as_bytes()[..4], &0x0403_0201u32.to_ne_bytes());
+    let before = words.hash_with(DefaultHasher::new());
+    assert_eq!(before, words.hash_with(DefaultHasher::new()));
+
+    words.as_bytes_mut()[7] ^= 0xff;
+    assert_ne!(before, words.hash_with(DefaultHasher::new()));
+
+    Ok(())
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_test() -> Result<(), ArrayError> {
+    use rand::{ SeedableRng, rngs::StdRng };
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut arr = Array::from_iter_exact(100, 0..100u32)?;
+
+    let picked = *arr.choose(&mut rng).unwrap();
+    assert!(picked < 100);
+    assert!(Array::<u32>::new(0)?.choose(&mut rng).is_none());
+
+    let sample = arr.choose_multiple(&mut rng, 10)?;
+    let mut seen = sample.as_slice().to_vec();
+    seen.sort();
+    seen.dedup();
+    assert_eq!(seen.len(), 10);
+    assert_eq!(arr.choose_multiple(&mut rng, 1000)?.cap(), 100);
+
+    let (front, rest) = arr.partial_shuffle(&mut rng, 5);
+    assert_eq!((front.len(), rest.len()), (5, 95));
+    let mut all = arr.as_slice().to_vec();
+    all.sort();
+    assert_eq!(all, (0..100).collect::<Vec<u32>>());
+
+    Ok(())
+}
+
+#[test]
+fn iter_adapters_test() -> Result<(), ArrayError> {
+    let arr = Array::from_iter_exact(1_000_000, 0..1_000_000u32)?;
+    let mut iter = arr.clone().into_iter();
+    assert_eq!(iter.nth(999_997), Some(999_997));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.nth(5), None);
+    assert_eq!(iter.next(), None);
+
+    assert_eq!(arr.clone().into_iter().skip(10).min(), Some(10));
+    assert_eq!(arr.clone().into_iter().skip(10).count(), 999_990);
+    assert_eq!(arr.into_iter().last(), Some(999_999));
+    assert_eq!(Array::<u32>::new(0)?.into_iter().last(), None);
+
+    Ok(())
+}
+
+#[test]
+fn iter_as_slice_test() -> Result<(), ArrayError> {
+    let mut arr: Array<u8> = "key=value".parse()?;
+
+    let mut owned = arr.clone().into_iter();
+    while owned.as_slice().first() != Some(&b'=') {
+        owned.next();
+    }
+    assert_eq!(owned.as_slice(), b"=value");
+
+    let mut borrowed = arr.iter();
+    borrowed.nth(3);
+    assert_eq!(borrowed.as_slice(), b"value");
+
+    for b in &mut arr {
+        *b = b.to_ascii_uppercase();
+    }
+    assert_eq!((&arr).into_iter().copied().collect::<Vec<u8>>(), b"KEY=VALUE");
+
+    Ok(())
+}
+
+#[test]
+fn collect_results_test() -> Result<(), ArrayError> {
+    let parsed: Result<Array<u32>, _> = "4 8 15 16 23 42".split(' ').map(str::parse).collect();
+    assert_eq!(parsed.unwrap().as_slice(), &[4, 8, 15, 16, 23, 42]);
+
+    let mut seen = 0;
+    let parsed: Result<Array<u32>, _> = "4 8 x 16".split(' ')
+        .inspect(|_| seen += 1)
+        .map(str::parse::<u32>)
+        .collect();
+    assert!(parsed.is_err());
+    assert_eq!(seen, 3);
+
+    let empty: Array<u32> = std::iter::empty().collect();
+    assert_eq!(empty.cap(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn chunks_mut_test() -> Result<(), ArrayError> {
+    let mut arr: Array<u64> = (0..10).into();
+    assert!(arr.chunks_mut(0).is_err());
+
+    std::thread::scope(|scope| -> Result<(), ArrayError> {
+        for chunk in arr.chunks_mut(4)? {
+            scope.spawn(move || chunk.iter_mut().for_each(|x| *x *= 10));
+        }
+        Ok(())
+    })?;
+    assert_eq!(arr.as_slice(), &[0, 10, 20, 30, 40, 50, 60, 70, 80, 90]);
+
+    let lens: Vec<usize> = arr.chunks(4)?.map(<[u64]>::len).collect();
+    assert_eq!(lens, [4, 4, 2]);
+
+    Ok(())
+}
+
+#[test]
+fn mask_test() -> Result<(), ArrayError> {
+    let mut arr: Array<i32> = [3, -1, 4, -1, 5, -9].iter().copied().collect();
+    let negative: Array<bool> = arr.iter().map(|&x| x < 0).collect();
+
+    assert_eq!(arr.select(&negative)?.as_slice(), &[-1, -1, -9]);
+    arr.mask_assign(&negative, 0)?;
+    assert_eq!(arr.as_slice(), &[3, 0, 4, 0, 5, 0]);
+
+    let short: Array<bool> = [true].iter().copied().collect();
+    assert!(arr.select(&short).is_err());
+    assert!(arr.mask_assign(&short, 1).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn gather_scatter_test() -> Result<(), ArrayError> {
+    let mut arr: Array<char> = "abcdef".parse()?;
+    let idxs: Array<usize> = [5, 0, 0, 2].iter().copied().collect();
+
+    assert_eq!(arr.gather(&idxs)?.as_slice(), &['f', 'a', 'a', 'c']);
+
+    let values: Array<char> = "XYZW".parse()?;
+    arr.scatter(&idxs, &values)?;
+    assert_eq!(String::from(arr.clone()), "ZbWdeX");
+
+    let bad: Array<usize> = [1, 6].iter().copied().collect();
+    assert!(arr.gather(&bad).is_err());
+    assert!(arr.scatter(&bad, &"QR".parse()?).is_err());
+    assert!(arr.scatter(&idxs, &"QR".parse()?).is_err());
+    assert_eq!(String::from(arr), "ZbWdeX");
+
+    Ok(())
+}
+
+#[test]
+fn permutation_test() -> Result<(), ArrayError> {
+    let keys: Array<u32> = [30, 10, 40, 20, 50].iter().copied().collect();
+    let mut names: Array<char> = "cadbe".parse()?;
+
+    let mut order: Vec<usize> = (0..keys.cap()).collect();
+    order.sort_by_key(|&i| keys.as_slice()[i]);
+    let order: Array<usize> = order.into_iter().collect();
+
+    assert_eq!(keys.permute(&order)?.as_slice(), &[10, 20, 30, 40, 50]);
+    names.apply_permutation(&order)?;
+    assert_eq!(String::from(names.clone()), "abcde");
+
+    let repeated: Array<usize> = [0, 1, 1, 2, 3].iter().copied().collect();
+    assert!(keys.permute(&repeated).is_err());
+    assert!(names.apply_permutation(&repeated).is_err());
+    assert!(names.apply_permutation(&Array::new(4)?).is_err());
+
+    let mut long: Array<u32> = (0..100).collect();
+    let perm: Array<usize> = (0..100).map(|i| (i * 37 + 11) % 100).collect();
+    let expected = long.permute(&perm)?;
+    long.apply_permutation(&perm)?;
+    assert_eq!(long, expected);
+
+    Ok(())
+}
+
+#[test]
+fn top_k_test() -> Result<(), ArrayError> {
+    let mut arr: Array<u32> = (0..100).map(|i| (i * 37 + 11) % 100).collect();
+
+    assert_eq!(arr.top_k(3)?.as_slice(), &[99, 98, 97]);
+    assert_eq!(arr.bottom_k(4)?.as_slice(), &[0, 1, 2, 3]);
+    assert_eq!(arr.top_k(0)?.cap(), 0);
+    assert_eq!(arr.bottom_k(100)?.as_slice(), (0..100).collect::<Vec<u32>>().as_slice());
+    assert!(arr.top_k(101).is_err());
+
+    let (below, median, above) = arr.select_nth_unstable(50)?;
+    assert_eq!(*median, 50);
+    assert!(below.iter().all(|&x| x < 50));
+    assert!(above.iter().all(|&x| x > 50));
+    assert!(arr.select_nth_unstable(100).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn histogram_test() -> Result<(), ArrayError> {
+    let rolls: Array<u8> = [1, 3, 3, 6, 1, 3].iter().copied().collect();
+    assert_eq!(rolls.bincount()?.as_slice(), &[0, 2, 0, 3, 0, 0, 1]);
+    assert_eq!(Array::<u32>::new(0)?.bincount()?.cap(), 0);
+
+    let samples: Array<f64> = [0.0, 0.1, 0.5, 0.99, 1.0, -0.5, 2.0, f64::NAN].iter().copied().collect();
+    assert_eq!(samples.histogram(4, (0.0, 1.0))?.as_slice(), &[2, 0, 1, 2]);
+    assert!(samples.histogram(0, (0.0, 1.0)).is_err());
+    assert!(samples.histogram(4, (1.0, 1.0)).is_err());
+    assert!(samples.histogram(4, (0.0, f64::INFINITY)).is_err());
+    assert!(samples.histogram(4, (f64::MIN, f64::MAX)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn unique_test() -> Result<(), ArrayError> {
+    let ids: Array<u32> = [7, 3, 7, 1, 3, 9, 1].iter().copied().collect();
+
+    assert_eq!(ids.unique().as_slice(), &[1, 3, 7, 9]);
+    assert_eq!(ids.unique_hashed().as_slice(), &[7, 3, 1, 9]);
+    assert_eq!(Array::<u32>::new(0)?.unique().cap(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn set_ops_test() -> Result<(), ArrayError> {
+    let ours: Array<u32> = [5, 1, 3, 3, 8].iter().copied().collect();
+    let theirs: Array<u32> = [8, 2, 3, 9, 2].iter().copied().collect();
+
+    assert_eq!(ours.intersection(&theirs).as_slice(), &[3, 8]);
+    assert_eq!(ours.union(&theirs).as_slice(), &[1, 2, 3, 5, 8, 9]);
+    assert_eq!(ours.difference(&theirs).as_slice(), &[1, 5]);
+    assert_eq!(theirs.difference(&ours).as_slice(), &[2, 9]);
+    assert_eq!(ours.intersection(&Array::new(0)?).cap(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn diff_test() -> Result<(), ArrayError> {
+    let before: Array<u32> = (0..1_000_000).collect();
+    let mut after = before.clone();
+    after.set(17, 0)?;
+    after.set(999_998, 0)?;
+
+    assert_eq!(before.diff(&after).as_slice(), &[17, 999_998]);
+    assert_eq!(before.diff(&before).cap(), 0);
+
+    let short: Array<u32> = (0..3).collect();
+    let long: Array<u32> = [0, 9, 2, 3, 4].iter().copied().collect();
+    assert_eq!(short.diff(&long).as_slice(), &[1, 3, 4]);
+    assert_eq!(long.diff(&short).as_slice(), &[1, 3, 4]);
+
+    Ok(())
+}
+
+#[test]
+fn rolling_test() -> Result<(), ArrayError> {
+    let series: Array<f64> = [1.0, 2.0, 3.0, 4.0, 6.0, 8.0].iter().copied().collect();
+
+    assert_eq!(series.rolling_sum(3)?.as_slice(), &[6.0, 9.0, 13.0, 18.0]);
+    assert_eq!(series.rolling_mean(2)?.as_slice(), &[1.5, 2.5, 3.5, 5.0, 7.0]);
+    assert_eq!(series.rolling_sum(6)?.as_slice(), &[24.0]);
+
+    let max = series.rolling(3, |w| w.iter().copied().fold(f64::MIN, f64::max))?;
+    assert_eq!(max.as_slice(), &[3.0, 4.0, 6.0, 8.0]);
+
+    assert!(series.rolling_sum(0).is_err());
+    assert!(series.rolling_mean(7).is_err());
+    assert!(series.rolling(7, |w| w.len()).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn convolve_test() -> Result<(), ArrayError> {
+    let signal: Array<i64> = [1, 2, 3].iter().copied().collect();
+    let kernel: Array<i64> = [0, 1, 2].iter().copied().collect();
+
+    assert_eq!(signal.convolve(&kernel, ConvolveMode::Full)?.as_slice(), &[0, 1, 4, 7, 6]);
+    assert_eq!(signal.convolve(&kernel, ConvolveMode::Same)?.as_slice(), &[1, 4, 7]);
+    assert_eq!(signal.convolve(&kernel, ConvolveMode::Valid)?.as_slice(), &[4]);
+    assert_eq!(signal.correlate(&kernel, ConvolveMode::Full)?.as_slice(), &[2, 5, 8, 3, 0]);
+
+    let smooth: Array<f64> = [0.25, 0.5, 0.25].iter().copied().collect();
+    let step: Array<f64> = [0.0, 0.0, 4.0, 4.0].iter().copied().collect();
+    assert_eq!(step.convolve(&smooth, ConvolveMode::Same)?.as_slice(), &[0.0, 1.0, 3.0, 3.0]);
+
+    // A longer kernel swaps the roles, like NumPy: full is [0, 1, 4, 10, 12, 9]
+    let long: Array<i64> = (0..4).collect();
+    assert_eq!(signal.convolve(&long, ConvolveMode::Same)?.as_slice(), &[1, 4, 10, 12]);
+    assert_eq!(signal.convolve(&long, ConvolveMode::Valid)?.as_slice(), &[4, 10]);
+    assert_eq!(long.convolve(&signal, ConvolveMode::Same)?, signal.convolve(&long, ConvolveMode::Same)?);
+    assert!(signal.convolve(&Array::new(0)?, ConvolveMode::Full).is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "rustfft")]
+#[test]
+fn fft_test() -> Result<(), ArrayError> {
+    use better_array::fft::Complex;
+
+    // cos(2πk/8) puts all of its energy in bins 1 and 7
+    let signal: Array<f64> = (0..8).map(|k| (std::f64::consts::PI * k as f64 / 4.0).cos()).collect();
+    let mut spectrum = signal.rfft()?;
+    let magnitudes: Vec<f64> = spectrum.iter().map(|bin| bin.norm().round()).collect();
+    assert_eq!(magnitudes, [0.0, 4.0, 0.0, 0.0, 0.0, 0.0, 0.0, 4.0]);
+
+    let ptr = spectrum.as_ptr();
+    spectrum.ifft();
+    assert_eq!(spectrum.as_ptr(), ptr);
+    for (round_trip, original) in spectrum.real_parts().iter().zip(signal.iter()) {
+        assert!((round_trip - original).abs() < 1e-12);
+    }
+
+    let mut pairs: Array<Complex<f32>> = (0..4).map(|re| Complex::new(re as f32, 0.0)).collect();
+    pairs.fft();
+    assert_eq!(pairs.as_slice()[0], Complex::new(6.0, 0.0));
+
+    Ok(())
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn ndarray_test() -> Result<(), ArrayError> {
+    use ndarray::{ s, Array1 };
+
+    let mut arr: Array<f64> = (1..=4).map(f64::from).collect();
+    assert_eq!(arr.as_ndarray().dot(&arr.as_ndarray()), 30.0);
+    arr.as_ndarray_mut().mapv_inplace(|x| x * 2.0);
+
+    let nd: Array1<f64> = arr.into();
+    assert_eq!(nd.to_vec(), [2.0, 4.0, 6.0, 8.0]);
+
+    #[cfg(not(feature = "debug-guard"))]
+    let ptr = nd.as_ptr();
+    let back = Array::from_ndarray(nd);
+    #[cfg(not(feature = "debug-guard"))]
+    assert_eq!(back.as_ptr() as *const f64, ptr);
+    assert_eq!(back.as_slice(), &[2.0, 4.0, 6.0, 8.0]);
+
+    let mut sliced = back.into_ndarray();
+    sliced.slice_collapse(s![1..3]);
+    assert_eq!(Array::from_ndarray(sliced).as_slice(), &[4.0, 6.0]);
+
+    let strided = Array1::from_vec((0..6).collect::<Vec<u32>>()).slice_move(s![..;2]);
+    assert_eq!(Array::from_ndarray(strided).as_slice(), &[0, 2, 4]);
+
+    Ok(())
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra_test() -> Result<(), ArrayError> {
+    use nalgebra::DVector;
+
+    let mut arr: Array<f64> = [3.0, 0.0, 4.0].iter().copied().collect();
+    assert_eq!(arr.as_dvector().norm(), 5.0);
+    arr.as_dvector_mut().scale_mut(2.0);
+
+    let vector: DVector<f64> = arr.into();
+    assert_eq!(vector.as_slice(), &[6.0, 0.0, 8.0]);
+
+    #[cfg(not(feature = "debug-guard"))]
+    let ptr = vector.as_ptr();
+    let back = Array::from_dvector(vector);
+    #[cfg(not(feature = "debug-guard"))]
+    assert_eq!(back.as_ptr() as *const f64, ptr);
+    assert_eq!(back.as_slice(), &[6.0, 0.0, 8.0]);
+
+    Ok(())
+}
+
+#[test]
+fn from_vec_test() -> Result<(), ArrayError> {
+    let words = vec![String::from("zero"), String::from("copy")];
+    #[cfg(not(feature = "debug-guard"))]
+    let ptr = words.as_ptr();
+
+    let arr = Array::from_vec(words);
+    #[cfg(not(feature = "debug-guard"))]
+    assert_eq!(arr.as_ptr() as *const String, ptr);
+    assert_eq!(arr.as_slice(), &["zero", "copy"]);
+
+    // Owned elements survive the trip through `From<Iterator>`
+    let upper: Array<String> = arr.iter().map(|w| w.to_uppercase()).into();
+    assert_eq!(upper.as_slice(), &["ZERO", "COPY"]);
+
+    Ok(())
+}
+
+#[test]
+fn array_pool_test() -> Result<(), ArrayError> {
+    let mut pool: ArrayPool<f32> = ArrayPool::new(2);
+
+    let mut frame = pool.take(1024)?;
+    frame.fill(1.0);
+    let ptr = frame.as_ptr();
+    pool.give_back(frame);
+    assert_eq!(pool.retained(), 1);
+
+    let reused = pool.take(1024)?;
+    assert_eq!(reused.as_ptr(), ptr);
+    assert_eq!(reused[0], 1.0);
+    assert_eq!(pool.take(512)?[0], 0.0);
+
+    for _ in 0..3 {
+        pool.give_back(Array::new(64)?);
+    }
+    assert_eq!(pool.retained(), 2);
+    pool.clear();
+    assert_eq!(pool.retained(), 0);
+
+    Ok(())
+}
+
+#[cfg(feature = "failpoints")]
+#[test]
+fn failpoints_test() -> Result<(), ArrayError> {
+    use better_array::failpoints;
+
+    let arr: Array<u32> = (0..4).collect();
+
+    failpoints::fail_next(2);
+    assert!(Array::<u32>::new(8).is_err());
+    assert!(arr.repeat(2).is_err());
+    assert_eq!(failpoints::pending(), 0);
+    assert_eq!(arr.repeat(2)?.cap(), 8);
+
+    failpoints::fail_next(1);
+    let cloned = std::panic::catch_unwind(|| arr.clone());
+    assert!(cloned.is_err());
+
+    failpoints::fail_next(5);
+    failpoints::clear();
+    assert!(Array::<u32>::new(8).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn element_access_test() -> Result<(), ArrayError> {
+    let mut arr: Array<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+
+    assert_eq!(arr.get(1).map(String::as_str), Some("b"));
+    assert_eq!(arr.get(3), None);
+    arr.get_mut(0).unwrap().push('!');
+    arr[2].push('?');
+    assert_eq!(arr[..], ["a!", "b", "c?"]);
+
+    assert_eq!(arr.get_checked(2)?, "c?");
+    let err = arr.get_checked(5).unwrap_err();
+    assert_eq!(err.msg(), "index 5 out of range for length 3");
+
+    let out_of_range = std::panic::catch_unwind(|| arr[3].len());
+    assert!(out_of_range.is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn tracing_test() -> Result<(), ArrayError> {
+    use {
+        std::{ fmt, sync::{ Arc, Mutex } },
+        tracing::{ field::{ Field, Visit }, span, Event, Metadata, Subscriber },
+    };
+
+    // Records the message of every event
+    struct Messages(Arc<Mutex<Vec<String>>>);
+
+    struct MessageVisitor<'a>(&'a mut String);
+
+    impl Visit for MessageVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            if field.name() == "message" {
+                *self.0 = format!("{:?}", value);
+            }
+        }
+    }
+
+    impl Subscriber for Messages {
+        fn enabled(&self, _: &Metadata<'_>) -> bool { true }
+        fn new_span(&self, _: &span::Attributes<'_>) -> span::Id { span::Id::from_u64(1) }
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+        fn enter(&self, _: &span::Id) {}
+        fn exit(&self, _: &span::Id) {}
+        fn event(&self, event: &Event<'_>) {
+            let mut message = String::new();
+            event.record(&mut MessageVisitor(&mut message));
+            self.0.lock().unwrap().push(message);
+        }
+    }
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    tracing::subscriber::with_default(Messages(messages.clone()), || -> Result<(), ArrayError> {
+        let arr = Array::<u64>::new(4)?;
+        let copy = arr.clone();
+        drop(arr);
+        drop(copy);
+        Ok(())
+    })?;
+    assert_eq!(*messages.lock().unwrap(), [
+        "array alloc", "array clone", "array alloc", "array dealloc", "array dealloc",
+    ]);
+
+    Ok(())
+}
+
+#[cfg(feature = "poison")]
+#[test]
+fn poison_test() -> Result<(), ArrayError> {
+    use better_array::poison::POISON;
+
+    let mut arr: Array<u32> = (1..=4).collect();
+    assert_eq!(arr.pop(1)?, 2);
+    assert_eq!(arr.get_checked(1).unwrap_err().kind(), ErrorKind::UseAfterRemove);
+    assert_eq!(arr.get(1), None);
+    assert_eq!(arr[1], u32::from_ne_bytes([POISON; 4]));
+    assert_eq!(arr.get_checked(2)?, &3);
+    assert_eq!(arr.get_checked(9).unwrap_err().kind(), ErrorKind::Other);
+
+    arr.set(1, 20)?;
+    assert_eq!(arr.get_checked(1)?, &20);
+    assert_eq!(arr.pop(0)?, 1);
+    arr.fill(7);
+    assert_eq!(arr.get(0), Some(&7));
+
+    // A live value that happens to be all poison bytes is still live
+    let mut bytes: Array<u8> = [POISON, 1].iter().copied().collect();
+    assert_eq!(bytes.get_checked(0)?, &POISON);
+    bytes.pop(1)?;
+    assert_eq!(bytes.get(0), Some(&POISON));
+    assert!(bytes.get(1).is_none());
+
+    let mut vec: VecMode<u16> = VecMode::new(4)?;
+    for x in 0..4 {
+        vec.push(x)?;
+    }
+    vec.truncate(1);
+    let raw = unsafe { std::slice::from_raw_parts(vec.as_slice().as_ptr(), 4) };
+    assert_eq!(raw, &[0, 0xDDDD, 0xDDDD, 0xDDDD]);
+
+    Ok(())
+}
+
+#[test]
+fn const_layout_test() -> Result<(), ArrayError> {
+    const TABLE: ArrayLayout = match ArrayLayout::of::<u64>(512) {
+        Some(layout) => layout,
+        None => panic!("table too large"),
+    };
+    static BUF: [u8; TABLE.size()] = [0; TABLE.size()];
+
+    assert_eq!(BUF.len(), 4096);
+    assert_eq!(TABLE.align(), 8);
+    assert_eq!(TABLE.to_layout(), std::alloc::Layout::array::<u64>(512).unwrap());
+    assert_eq!(ArrayLayout::of::<u64>(usize::MAX / 4), None);
+
+    let arr = Array::<u64>::new(512)?;
+    assert_eq!(arr.layout(), TABLE);
+    assert!(!arr.is_empty());
+    assert!(Array::<u64>::new(0)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn raw_parts_test() -> Result<(), ArrayError> {
+    use std::mem::{ align_of, offset_of, size_of };
+
+    assert_eq!(size_of::<Array<u8>>(), 2 * size_of::<usize>());
+    assert_eq!(size_of::<ArrayRawParts<u8>>(), size_of::<Array<u8>>());
+    assert_eq!(align_of::<ArrayRawParts<u8>>(), align_of::<Array<u8>>());
+    assert_eq!(offset_of!(ArrayRawParts<u8>, cap), size_of::<*mut u8>());
+
+    let arr: Array<u16> = (0..8).collect();
+    let ptr = arr.as_ptr();
+    let parts = arr.into_raw_parts();
+    assert_eq!((parts.ptr, parts.cap), (ptr, 8));
+
+    let back = unsafe { Array::from_raw_parts(parts) };
+    assert_eq!(back.as_slice(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+
+    Ok(())
+}
+
+#[cfg(feature = "wasm")]
+#[test]
+fn wasm_array_test() -> Result<(), ArrayError> {
+    use better_array::wasm::WasmF64Array;
+
+    // Typed array views need a JS host, so only the Rust side is checked here
+    let mut samples = WasmF64Array::from(Array::<f64>::new(128)?);
+    samples.as_array_mut().fill(0.5);
+    assert_eq!(samples.length(), 128);
+    assert_eq!(samples.as_array()[127], 0.5);
+
+    Ok(())
+}
+
+#[cfg(feature = "python")]
+#[test]
+fn python_buffer_test() -> Result<(), ArrayError> {
+    use {
+        better_array::python::PyF64Array,
+        pyo3::{ prelude::*, types::PyDict },
+    };
+
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| -> PyResult<()> {
+        let arr: Array<f64> = [1.0, 2.0, 3.0].iter().copied().collect();
+        let obj = Py::new(py, PyF64Array::from(arr))?;
+        let locals = PyDict::new(py);
+        locals.set_item("arr", &obj)?;
+        let code = b"view = memoryview(arr)\nview[1] = 20.0\nformat, shape, total = view.format, view.shape, sum(view)\nview.release()\n\0";
+        py.run(std::ffi::CStr::from_bytes_with_nul(code).unwrap(), None, Some(&locals))?;
+
+        assert_eq!(locals.get_item("format")?.unwrap().extract::<String>()?, "d");
+        assert_eq!(locals.get_item("shape")?.unwrap().extract::<(usize,)>()?, (3,));
+        assert_eq!(locals.get_item("total")?.unwrap().extract::<f64>()?, 24.0);
+        assert_eq!(obj.borrow(py).as_array().as_slice(), &[1.0, 20.0, 3.0]);
+        Ok(())
+    }).map_err(|e| ArrayError::new(e.to_string()))
+}
+
+#[test]
+fn dlpack_test() -> Result<(), ArrayError> {
+    use better_array::dlpack::{ DlpackTensor, DL_FLOAT };
+
+    let arr: Array<f32> = (0..6).map(|x| x as f32).collect();
+    let ptr = arr.as_ptr();
+
+    let managed = arr.to_dlpack();
+    let tensor = unsafe { &(*managed).dl_tensor };
+    assert_eq!(tensor.data as *mut f32, ptr);
+    assert_eq!(unsafe { *tensor.shape }, 6);
+    assert_eq!((tensor.dtype.code, tensor.dtype.bits), (DL_FLOAT, 32));
+
+    let view = unsafe { DlpackTensor::<f32>::from_raw(managed)? };
+    assert_eq!(view.as_ptr(), ptr as *const f32);
+    assert_eq!(view.iter().sum::<f32>(), 15.0);
+    drop(view);
+
+    let copied = unsafe { Array::<f32>::from_dlpack(Array::<f32>::new(3)?.to_dlpack())? };
+    assert_eq!(copied.as_slice(), &[0.0; 3]);
+    assert!(unsafe { Array::<f64>::from_dlpack(copied.to_dlpack()) }.is_err());
+
+    let corrupt = |f: fn(&mut better_array::dlpack::DLTensor)| -> Result<bool, ArrayError> {
+        let managed = Array::<f32>::new(4)?.to_dlpack();
+        unsafe {
+            f(&mut (*managed).dl_tensor);
+            Ok(DlpackTensor::<f32>::from_raw(managed).is_err())
+        }
+    };
+    assert!(corrupt(|t| unsafe { *t.shape = -1 })?);
+    assert!(corrupt(|t| t.data = std::ptr::null_mut())?);
+    assert!(corrupt(|t| t.byte_offset = 2)?);
+    assert!(!corrupt(|t| unsafe {
+        t.byte_offset = 4;
+        *t.shape = 3;
+    })?);
+
+    Ok(())
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn async_io_test() -> Result<(), ArrayError> {
+    let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    rt.block_on(async {
+        let (mut client, mut server) = tokio::io::duplex(64);
+
+        let frame: Array<u32> = (1..=4).collect();
+        frame.write_all_to(&mut client).await.unwrap();
+
+        let mut received = Array::<u32>::new(4)?;
+        received.read_exact_from(&mut server).await.unwrap();
+        assert_eq!(received, frame);
+
+        drop(client);
+        let mut header = Array::<u8>::new(1)?;
+        let err = header.read_exact_from(&mut server).await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+        Ok(())
+    })
+}
+
+#[test]
+fn chunked_array_test() -> Result<(), ArrayError> {
+    let path = std::env::temp_dir().join(format!("better-array-chunked-{}", std::process::id()));
+
+    {
+        let mut arr: ChunkedArray<u64> = ChunkedArray::create(&path, 1000, 64, 2)?;
+        for i in 0..arr.len() {
+            arr.set(i, i as u64 * 3)?;
+        }
+        assert_eq!(arr.resident_chunks(), 2);
+        assert_eq!(arr.get(5)?, 15);
+        assert_eq!(arr.get(999)?, 2997);
+        assert!(arr.get(1000).is_err());
+    }
+
+    let mut arr: ChunkedArray<u64> = ChunkedArray::open(&path, 100, 1)?;
+    assert_eq!(arr.len(), 1000);
+    let sum = arr.iter().sum::<Result<u64, ArrayError>>()?;
+    assert_eq!(sum, 3 * 999 * 1000 / 2);
+    assert_eq!(arr.resident_chunks(), 1);
+
+    drop(arr);
+    std::fs::remove_file(&path).map_err(ArrayError::new)?;
+    Ok(())
+}
+
+#[test]
+fn chunked_eviction_test() -> Result<(), ArrayError> {
+    let path = std::env::temp_dir().join(format!("better-array-evict-{}", std::process::id()));
+
+    let mut arr: ChunkedArray<u32> = ChunkedArray::create(&path, 8, 4, 1)?;
+    arr.set(1, 0xABCD)?;
+    // Loading the second chunk evicts the dirty first one
+    assert_eq!(arr.get(6)?, 0);
+    assert_eq!(arr.resident_chunks(), 1);
+
+    let on_disk = std::fs::read(&path).map_err(ArrayError::new)?;
+    assert_eq!(on_disk[4..8], 0xABCDu32.to_ne_bytes());
+    assert_eq!(arr.get(1)?, 0xABCD);
+
+    drop(arr);
+    std::fs::remove_file(&path).map_err(ArrayError::new)?;
+    Ok(())
+}
+
+#[test]
+fn persistent_array_test() -> Result<(), ArrayError> {
+    let state: Vec<u32> = (0..5000).collect();
+    let v0 = PersistentArray::from_slice(&state)?;
+    let v1 = v0.set(4321, 0)?;
+    let v2 = v1.set(0, 7)?.set(4999, 8)?;
+
+    assert_eq!(v0.get(4321), Some(&4321));
+    assert_eq!(v1.get(4321), Some(&0));
+    assert_eq!((v2.get(0), v2.get(4321), v2.get(4999)), (Some(&7), Some(&0), Some(&8)));
+    assert_eq!(v1.get(5000), None);
+    assert!(v1.set(5000, 1).is_err());
+    assert!(v0.iter().copied().eq(0..5000));
+
+    let small = PersistentArray::from_slice(&["a".to_string(), "b".to_string()])?;
+    let changed = small.set(1, "c".to_string())?;
+    assert_eq!(small.iter().chain(changed.iter()).cloned().collect::<Vec<_>>(), ["a", "b", "a", "c"]);
+    assert!(PersistentArray::<u8>::from_slice(&[])?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn versioned_array_test() -> Result<(), ArrayError> {
+    let mut doc = VersionedArray::new("abcd".parse::<Array<char>>()?);
+
+    doc.set(0, 'x')?;
+    doc.set(1, 'y')?;
+    doc.checkpoint();
+    doc.fill('-');
+    assert_eq!(doc.pop(3)?, '-');
+    assert!(doc.set(4, 'z').is_err());
+    assert_eq!(doc.as_slice()[..3], ['-', '-', '-']);
+
+    assert!(doc.undo());
+    assert_eq!(doc.as_slice(), &['x', 'y', 'c', 'd']);
+    assert!(doc.undo());
+    assert_eq!(doc.as_slice(), &['a', 'b', 'c', 'd']);
+    assert!(!doc.undo());
+
+    assert!(doc.redo());
+    assert_eq!(doc.as_slice(), &['x', 'y', 'c', 'd']);
+    doc.set(2, 'q')?;
+    assert!(!doc.redo());
+    assert_eq!(String::from(doc.into_array()), "xyqd");
+
+    Ok(())
+}
+
+#[test]
+fn transaction_test() -> Result<(), ArrayError> {
+    let mut balances: Array<i64> = [100, 50, 0].iter().copied().collect();
+
+    let transfer = |balances: &mut Array<i64>, from: usize, to: usize, amount: i64| {
+        balances.transaction(|txn| {
+            let remaining = txn.get(from).copied().unwrap_or(0) - amount;
+            txn.set(from, remaining)?;
+            if remaining < 0 {
+                return Err(ArrayError::new("insufficient funds"));
+            }
+            let received = txn.get(to).copied().unwrap_or(0) + amount;
+            txn.set(to, received)?;
+            Ok(txn.staged())
+        })
+    };
+
+    assert_eq!(transfer(&mut balances, 0, 2, 30)?, 2);
+    assert_eq!(balances.as_slice(), &[70, 50, 30]);
+
+    assert!(transfer(&mut balances, 1, 2, 80).is_err());
+    assert!(transfer(&mut balances, 1, 3, 10).is_err());
+    assert_eq!(balances.as_slice(), &[70, 50, 30]);
+
+    Ok(())
+}
+
+#[test]
+fn observed_array_test() -> Result<(), ArrayError> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut arr = ObservedArray::new((1..=3).collect::<Array<u32>>(), move |change| {
+        let event = match change {
+            Change::Set { idx, old, new } => (idx, *old, Some(*new)),
+            Change::Pop { idx, old } => (idx, *old, None),
+        };
+        tx.send(event).unwrap();
+    });
+
+    arr.set(0, 10)?;
+    assert_eq!(arr.pop(2)?, 3);
+    assert!(arr.set(3, 0).is_err());
+    drop(arr);
+
+    assert_eq!(rx.iter().collect::<Vec<_>>(), [(0, 1, Some(10)), (2, 3, None)]);
+
+    let mut log = Vec::new();
+    let mut arr = ObservedArray::new((0..2).collect::<Array<u32>>(), |change| {
+        if let Change::Set { idx, new, .. } = change {
+            log.push((idx, *new));
+        }
+    });
+    arr.fill(7);
+    assert_eq!(arr.as_slice(), &[7, 7]);
+    drop(arr);
+    assert_eq!(log, [(0, 7), (1, 7)]);
+
+    Ok(())
+}
+
+#[test]
+fn validated_array_test() -> Result<(), ArrayError> {
+    struct Kelvin;
+
+    impl Validator<f64> for Kelvin {
+        fn validate(&self, val: &f64) -> Result<(), String> {
+            if *val >= 0.0 { Ok(()) } else { Err(format!("{} K is below absolute zero", val)) }
+        }
+    }
+
+    let mut temps = ValidatedArray::new(Array::<f64>::new(3)?, Kelvin)?;
+    temps.set(1, 300.0)?;
+    let err = temps.set(2, -1.0).unwrap_err();
+    assert_eq!(err.msg(), "constraint violation at index 2: -1 K is below absolute zero");
+    assert!(temps.fill(-5.0).unwrap_err().msg().starts_with("constraint violation in fill value"));
+    assert_eq!(temps.as_slice(), &[0.0, 300.0, 0.0]);
+
+    let bad: Array<f64> = [1.0, -2.0].iter().copied().collect();
+    assert!(ValidatedArray::new(bad, Kelvin).is_err());
+
+    let mut percents = ValidatedArray::new(Array::<u8>::new(2)?, |p: &u8| {
+        if *p <= 100 { Ok(()) } else { Err("over 100%".to_string()) }
+    })?;
+    percents.fill(100)?;
+    assert!(percents.set(0, 101).is_err());
+    assert_eq!(percents.get(0), Some(&100));
+
+    Ok(())
+}
+
+#[test]
+fn array_cell_test() -> Result<(), ArrayError> {
+    let degree = ArrayCell::new(4, 0u32)?;
+    let edges = [(0, 1), (1, 2), (1, 3)];
+
+    let bump = |node: usize| degree.update(node, |d| d + 1);
+    for &(a, b) in &edges {
+        bump(a)?;
+        bump(b)?;
+    }
+    degree.swap(0, 1)?;
+    assert!(degree.set(4, 1).is_err());
+    assert!(degree.swap(0, 9).is_err());
+
+    let tail = &degree.as_slice_of_cells()[2..];
+    tail[0].set(10);
+    assert_eq!(degree.get(2), Some(10));
+    assert_eq!(degree.into_array().as_slice(), &[3, 1, 10, 1]);
+
+    Ok(())
+}
+
+#[test]
+fn swap_with_test() -> Result<(), ArrayError> {
+    let mut front: Array<u8> = "next".parse()?;
+    let mut back: Array<u8> = "prev".parse()?;
+    let front_ptr = front.as_ptr();
+
+    front.swap_with_array(&mut back)?;
+    assert_eq!((front.as_slice(), back.as_slice()), (&b"prev"[..], &b"next"[..]));
+    assert_eq!(back.as_ptr(), front_ptr);
+
+    let mut staging = *b"cafe";
+    front.swap_with_slice(&mut staging)?;
+    assert_eq!((front.as_slice(), &staging), (&b"cafe"[..], b"prev"));
+
+    assert!(front.swap_with_slice(&mut [0; 3]).is_err());
+    assert!(front.swap_with_array(&mut Array::new(5)?).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn fixed_chunks_test() -> Result<(), ArrayError> {
+    let mut packet: Array<u8> = (0..40).collect();
+
+    let header: &[u8; 16] = packet.first_chunk().unwrap();
+    assert_eq!(u32::from_le_bytes([header[0], header[1], header[2], header[3]]), 0x0302_0100);
+    assert_eq!(packet.last_chunk::<2>(), Some(&[38, 39]));
+    assert_eq!(packet.first_chunk::<41>(), None);
+
+    let (records, rest) = packet.as_chunks::<16>();
+    assert_eq!((records.len(), rest), (2, &[32, 33, 34, 35, 36, 37, 38, 39][..]));
+    assert_eq!(records[1][0], 16);
+
+    for record in packet.as_chunks_mut::<8>().0 {
+        record.reverse();
+    }
+    assert_eq!(packet.first_chunk(), Some(&[7, 6, 5, 4, 3, 2, 1, 0]));
+
+    Ok(())
+}
+
+#[test]
+fn array_builder_test() -> Result<(), ArrayError> {
+    let mut builder = ArrayBuilder::new(3)?;
+    for word in ["alpha", "beta", "gamma"] {
+        builder.push(String::from(word)).unwrap();
+    }
+    assert_eq!(builder.push(String::from("delta")), Err(String::from("delta")));
+    assert_eq!(builder.remaining(), 0);
+    let words = builder.finish().ok().unwrap();
+    assert_eq!(words[2], "gamma");
+
+    let mut partial = ArrayBuilder::new(4)?;
+    partial.push(vec![1u8]).unwrap();
+    partial.push(vec![2, 3]).unwrap();
+    assert_eq!((partial.len(), partial.remaining()), (2, 2));
+    let partial = partial.finish().err().unwrap();
+    assert_eq!(partial.as_slice(), &[vec![1], vec![2, 3]]);
+    let prefix = partial.finish_prefix()?;
+    assert_eq!(prefix.cap(), 2);
+    assert_eq!(prefix[1], vec![2, 3]);
+
+    Ok(())
+}
+
+#[test]
+fn try_into_array_test() -> Result<(), ArrayError> {
+    let rgb: Array<u8> = [255, 128, 0].iter().copied().collect();
+    let rgb = rgb.try_into_array::<4>().unwrap_err();
+    let [r, g, b] = rgb.try_into_array().ok().unwrap();
+    assert_eq!((r, g, b), (255, 128, 0));
+
+    let names: Array<String> = ["x", "y"].iter().map(|s| s.to_string()).collect();
+    let boxed: Box<[String; 2]> = names.try_into_boxed_array().ok().unwrap();
+    assert_eq!(boxed[1], "y");
+
+    Ok(())
+}
+
+#[test]
+fn to_vec_test() -> Result<(), ArrayError> {
+    let tags: Array<String> = ["red", "green"].iter().map(|s| s.to_string()).collect();
+    let mut owned = tags.to_vec();
+    assert_eq!(owned.capacity(), 2);
+    owned.push(String::from("blue"));
+    assert_eq!(owned, ["red", "green", "blue"]);
+    assert_eq!(tags.as_slice(), &owned[..2]);
+
+    Ok(())
+}
+
+#[test]
+fn ptr_range_test() -> Result<(), ArrayError> {
+    let mut samples: Array<u32> = Array::new(6)?;
+    let range = samples.as_ptr_range();
+    assert_eq!(range.end as usize - range.start as usize, 6 * size_of::<u32>());
+    let inner = samples.as_slice()[2..4].as_ptr();
+    assert!(range.contains(&inner));
+
+    let end = samples.as_mut_ptr_range().end;
+    unsafe { end.sub(1).write(7) };
+    unsafe { samples.as_mut_ptr().write(1) };
+    assert_eq!((samples[0], samples[5]), (1, 7));
+
+    let empty: Array<u32> = Array::new(0)?;
+    assert!(empty.as_ptr_range().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn array_heap_test() -> Result<(), ArrayError> {
+    use std::cmp::Reverse;
+
+    let mut jobs = ArrayHeap::new(4)?;
+    for (priority, name) in [(2, "index"), (9, "page"), (5, "email"), (9, "alert")] {
+        jobs.push((priority, name)).unwrap();
+    }
+    assert!(jobs.is_full());
+    assert_eq!(jobs.push((1, "late")), Err((1, "late")));
+    assert_eq!(jobs.peek(), Some(&(9, "page")));
+
+    let order: Vec<_> = std::iter::from_fn(|| jobs.pop()).map(|(_, name)| name).collect();
+    assert_eq!(order, ["page", "alert", "email", "index"]);
+    assert!(jobs.pop().is_none());
+
+    let mut smallest = ArrayHeap::new(8)?;
+    for n in [7, 3, 8, 1, 4] {
+        smallest.push(Reverse(n.to_string())).unwrap();
+    }
+    assert_eq!(smallest.pop(), Some(Reverse(String::from("1"))));
+    assert_eq!(smallest.len(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn bloom_filter_test() -> Result<(), ArrayError> {
+    let mut seen: BloomFilter<str> = BloomFilter::new(1000, 0.01)?;
+    assert!(seen.bits() > 9000 && seen.hashes() == 7);
+    for id in 0..1000 {
+        seen.insert(&format!("user-{}", id));
+    }
+    assert!((0..1000).all(|id| seen.contains(&format!("user-{}", id))));
+    let false_positives = (1000..11000).filter(|id| seen.contains(&format!("user-{}", id))).count();
+    assert!(false_positives < 300, "{} false positives", false_positives);
+
+    let mut other = BloomFilter::new(1000, 0.01)?;
+    other.insert("admin");
+    assert!(!seen.contains("admin"));
+    seen.merge(&other)?;
+    assert!(seen.contains("admin"));
+
+    let small: BloomFilter<str> = BloomFilter::new(10, 0.01)?;
+    assert!(seen.merge(&small).is_err());
+    assert!(BloomFilter::<str>::new(10, 1.0).is_err());
+
+    seen.clear();
+    assert!(!seen.contains("user-1"));
+
+    Ok(())
+}
+
+#[test]
+fn lru_cache_test() -> Result<(), ArrayError> {
+    let mut sessions = LruCache::new(3)?;
+    assert_eq!(sessions.put(String::from("ann"), 1), None);
+    sessions.put(String::from("bob"), 2);
+    sessions.put(String::from("cat"), 3);
+
+    // Reading "ann" makes "bob" the least recently used
+    assert_eq!(sessions.get("ann"), Some(&1));
+    assert_eq!(sessions.put(String::from("dan"), 4), Some((String::from("bob"), 2)));
+    assert!(!sessions.contains("bob"));
+
+    assert_eq!(sessions.put(String::from("cat"), 30), Some((String::from("cat"), 3)));
+    *sessions.get_mut("dan").unwrap() += 40;
+    let order: Vec<_> = sessions.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+    assert_eq!(order, [("dan", 44), ("cat", 30), ("ann", 1)]);
+
+    assert_eq!(sessions.peek("ann"), Some(&1));
+    assert_eq!(sessions.remove("cat"), Some(30));
+    sessions.put(String::from("eve"), 5);
+    sessions.put(String::from("fay"), 6);
+    assert_eq!(sessions.len(), 3);
+    assert_eq!(sessions.peek("ann"), None);
+
+    Ok(())
+}
+
+#[test]
+fn sort_floats_test() -> Result<(), ArrayError> {
+    let mut readings: Array<f32> = [3.5, f32::NAN, -1.0, 0.0, -f32::NAN, -0.0, f32::NEG_INFINITY]
+        .iter().copied().collect();
+    readings.sort_floats();
+    assert_eq!(&readings[..5], &[f32::NEG_INFINITY, -1.0, -0.0, 0.0, 3.5]);
+    assert!(readings[3].is_sign_positive() && readings[2].is_sign_negative());
+    assert!(readings[5].is_nan() && readings[6].is_nan());
+
+    let mut wide: Array<f64> = [2.0, f64::NAN, 1.0].iter().copied().collect();
+    wide.sort_floats();
+    assert_eq!(&wide[..2], &[1.0, 2.0]);
+
+    Ok(())
+}
+
+#[test]
+fn overflow_arithmetic_test() -> Result<(), ArrayError> {
+    let mut phase: Array<u8> = [250, 10, 128].iter().copied().collect();
+    let step: Array<u8> = [10, 10, 200].iter().copied().collect();
+    phase.wrapping_add_assign(&step)?;
+    assert_eq!(phase.as_slice(), &[4, 20, 72]);
+
+    let mut gain: Array<i16> = [20_000, -20_000, 100].iter().copied().collect();
+    gain.saturating_mul_scalar(2);
+    assert_eq!(gain.as_slice(), &[i16::MAX, i16::MIN, 200]);
+    gain.saturating_sub_scalar(i16::MAX);
+    assert_eq!(gain.as_slice(), &[0, i16::MIN, 200 - i16::MAX]);
+
+    let short: Array<u8> = Array::new(2)?;
+    assert!(phase.saturating_add_assign(&short).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn map_in_place_test() -> Result<(), ArrayError> {
+    let mut paths: Array<String> = ["src", "tests"].iter().map(|s| s.to_string()).collect();
+    paths.map_in_place(|mut p| {
+        p.push('/');
+        p
+    });
+    assert_eq!(paths.as_slice(), &["src/", "tests/"]);
+
+    let mut counts: Array<u32> = (1..=4).collect();
+    let mut running = 0;
+    counts.for_each_mut(|c| {
+        running += *c;
+        *c = running;
+    });
+    assert_eq!(counts.as_slice(), &[1, 3, 6, 10]);
+
+    Ok(())
+}
+
+#[test]
+fn fold_reduce_scan_test() -> Result<(), ArrayError> {
+    let words: Array<String> = ["fold", "by", "reference"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(words.fold(0, |total, w| total + w.len()), 15);
+    assert_eq!(words.reduce(|acc, w| acc + "-" + w), Some(String::from("fold-by-reference")));
+    assert_eq!(Array::<String>::new(0)?.reduce(|acc, _| acc), None);
+
+    let offsets = words.scan_into(0, |start, w| {
+        let at = *start;
+        *start += w.len() + 1;
+        at
+    });
+    assert_eq!(offsets.as_slice(), &[0, 5, 8]);
+
+    Ok(())
+}
+
+#[test]
+fn array_writer_test() -> Result<(), ArrayError> {
+    use std::io::{ ErrorKind, Write };
+
+    let mut out = ArrayWriter::new(16)?;
+    write!(out, "id={};", 42).unwrap();
+    out.write_all(b"ok").unwrap();
+    assert_eq!(out.as_written(), b"id=42;ok");
+    assert_eq!((out.written(), out.remaining()), (8, 8));
+
+    let err = out.write_all(b"0123456789").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+    assert_eq!(out.written(), 16);
+
+    let buf = out.into_inner();
+    assert_eq!(&buf[..10], b"id=42;ok01");
+
+    Ok(())
+}
+
+#[test]
+fn read_into_test() -> Result<(), ArrayError> {
+    use std::io::{ Cursor, ErrorKind };
+
+    let bytes: Vec<u8> = [1u32, 2, 3, 4, 5].iter().flat_map(|v| v.to_ne_bytes()).collect();
+    let mut file = Cursor::new(bytes);
+
+    let mut first: Array<u32> = Array::new(2)?;
+    first.read_exact_into(&mut file).unwrap();
+    assert_eq!(first.as_slice(), &[1, 2]);
+
+    let mut a: Array<u32> = Array::new(1)?;
+    let mut b: Array<u32> = Array::new(2)?;
+    let read = Array::read_vectored_into(&mut file, &mut [&mut a, &mut b]).unwrap();
+    assert_eq!(read, 12);
+    assert_eq!((a[0], b.as_slice()), (3, &[4, 5][..]));
+
+    let err = first.read_exact_into(&mut file).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    Ok(())
+}
+
+#[test]
+fn cache_padded_test() -> Result<(), ArrayError> {
+    use std::sync::atomic::{ AtomicU64, Ordering };
+
+    let counters: Array<CachePadded<AtomicU64>> = (0..4).map(|_| CachePadded::new(AtomicU64::new(0))).collect();
+    let addr = |i: usize| &counters[i] as *const _ as usize;
+    assert_eq!(addr(1) - addr(0), 128);
+    assert_eq!(addr(0) % 128, 0);
+
+    std::thread::scope(|s| {
+        for (t, counter) in counters.iter().enumerate() {
+            s.spawn(move || {
+                for _ in 0..1000 {
+                    counter.fetch_add(t as u64, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    assert_eq!(counters[3].load(Ordering::Relaxed), 3000);
+
+    Ok(())
+}
+
+#[test]
+fn prefetch_test() -> Result<(), ArrayError> {
+    let table: Array<u64> = (0..1024).map(|v| v * 3).collect();
+    table.prefetch(1000);
+    table.prefetch(5000);
+
+    let idxs: Array<usize> = [900, 3, 512, 3, 17].iter().copied().collect();
+    let picked = table.gather_prefetch(&idxs, 2)?;
+    assert_eq!(picked, table.gather(&idxs)?);
+    assert_eq!(picked.as_slice(), &[2700, 9, 1536, 9, 51]);
+    assert_eq!(table.gather_prefetch(&idxs, 100)?, picked);
+
+    let bad: Array<usize> = [1, 1024].iter().copied().collect();
+    assert!(table.gather_prefetch(&bad, 1).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn checked_array_test() -> Result<(), ArrayError> {
+    use std::panic::catch_unwind;
+
+    let outcome = catch_unwind(|| -> Result<(), ArrayError> {
+        let mut frame = CheckedArray::try_from_iter(b"codec".iter().copied())?;
+        assert_eq!(frame.get(5), None);
+        assert!(frame.get_mut(usize::MAX).is_none());
+        assert!(frame.replace(9, b'!').is_err());
+        assert_eq!(frame.replace(0, b'C')?, b'c');
+        assert_eq!(frame.slice(3..9), None);
+        assert_eq!(frame.slice(1..3), Some(&b"od"[..]));
+        assert_eq!(frame.try_clone()?.as_slice(), b"Codec");
+        assert!(CheckedArray::<u64>::new(usize::MAX).is_err());
+        Ok(())
+    });
+    outcome.expect("CheckedArray panicked")?;
+
+    let mut names: Array<String> = ["ada", "bo"].iter().map(|s| s.to_string()).collect();
+    let mut copy = names.try_clone()?;
+    copy[0].push_str("-copy");
+    assert_eq!(copy.pop(1)?, "bo");
+    names[1].push('!');
+    assert_eq!((names[0].as_str(), names[1].as_str()), ("ada", "bo!"));
+    assert_eq!(copy[0], "ada-copy");
+
+    assert!(Array::from_iter_exact(3, 0..2u8).is_err());
+    assert!(Array::from_iter_exact(3, 0..4u8).is_err());
+
+    #[cfg(feature = "failpoints")]
+    {
+        let frame = CheckedArray::from_array(Array::<u8>::new(4)?);
+        better_array::failpoints::fail_next(1);
+        assert!(frame.try_clone().is_err());
+
+        better_array::failpoints::fail_next(1);
+        let built = catch_unwind(|| CheckedArray::try_from_iter(vec![1u8, 2, 3]).is_err());
+        better_array::failpoints::clear();
+        assert!(built.expect("try_from_iter panicked"));
+    }
+
     Ok(())
 }